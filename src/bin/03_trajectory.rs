@@ -1,550 +1,7 @@
-/*!
-# Day 3: Toboggan Trajectory
+//! Runs the solution for day 3; see `aoc20::day03` for the puzzle.
 
-With the toboggan login problems resolved, you set off toward the airport.
-While travel by toboggan might be easy, it's certainly not safe: there's very
-minimal steering and the area is covered in trees. You'll need to see which
-angles will take you near the fewest trees.
+use std::process::ExitCode;
 
-Due to the local geology, trees in this area only grow on exact integer
-coordinates in a grid. You make a map (your puzzle input) of the open squares
-(.) and trees (#) you can see. For example:
-
-..##.......
-#...#...#..
-.#....#..#.
-..#.#...#.#
-.#...##..#.
-..#.##.....
-.#.#.#....#
-.#........#
-#.##...#...
-#...##....#
-.#..#...#.#
-
-These aren't the only trees, though; due to something you read about once
-involving arboreal genetics and biome stability, the same pattern repeats to
-the right many times:
-
-..##.........##.........##.........##.........##.........##.......  --->
-#...#...#..#...#...#..#...#...#..#...#...#..#...#...#..#...#...#..
-.#....#..#..#....#..#..#....#..#..#....#..#..#....#..#..#....#..#.
-..#.#...#.#..#.#...#.#..#.#...#.#..#.#...#.#..#.#...#.#..#.#...#.#
-.#...##..#..#...##..#..#...##..#..#...##..#..#...##..#..#...##..#.
-..#.##.......#.##.......#.##.......#.##.......#.##.......#.##.....  --->
-.#.#.#....#.#.#.#....#.#.#.#....#.#.#.#....#.#.#.#....#.#.#.#....#
-.#........#.#........#.#........#.#........#.#........#.#........#
-#.##...#...#.##...#...#.##...#...#.##...#...#.##...#...#.##...#...
-#...##....##...##....##...##....##...##....##...##....##...##....#
-.#..#...#.#.#..#...#.#.#..#...#.#.#..#...#.#.#..#...#.#.#..#...#.#  --->
-
-You start on the open square (.) in the top-left corner and need to reach the
-bottom (below the bottom-most row on your map).
-
-The toboggan can only follow a few specific slopes (you opted for a cheaper
-model that prefers rational numbers); start by counting all the trees you would
-encounter for the slope right 3, down 1:
-
-From your starting position at the top-left, check the position that is right
-3 and down 1. Then, check the position that is right 3 and down 1 from there,
-and so on until you go past the bottom of the map.
-
-The locations you'd check in the above example are marked here with O where
-there was an open square and X where there was a tree:
-
-..##.........##.........##.........##.........##.........##.......  --->
-#..O#...#..#...#...#..#...#...#..#...#...#..#...#...#..#...#...#..
-.#....X..#..#....#..#..#....#..#..#....#..#..#....#..#..#....#..#.
-..#.#...#O#..#.#...#.#..#.#...#.#..#.#...#.#..#.#...#.#..#.#...#.#
-.#...##..#..X...##..#..#...##..#..#...##..#..#...##..#..#...##..#.
-..#.##.......#.X#.......#.##.......#.##.......#.##.......#.##.....  --->
-.#.#.#....#.#.#.#.O..#.#.#.#....#.#.#.#....#.#.#.#....#.#.#.#....#
-.#........#.#........X.#........#.#........#.#........#.#........#
-#.##...#...#.##...#...#.X#...#...#.##...#...#.##...#...#.##...#...
-#...##....##...##....##...#X....##...##....##...##....##...##....#
-.#..#...#.#.#..#...#.#.#..#...X.#.#..#...#.#.#..#...#.#.#..#...#.#  --->
-
-In this example, traversing the map using this slope would cause you to
-encounter 7 trees.
-
-Starting at the top-left corner of your map and following a slope of right
-3 and down 1, how many trees would you encounter?
-
-## Part Two
-
-Time to check the rest of the slopes - you need to minimize the probability of
-a sudden arboreal stop, after all.
-
-Determine the number of trees you would encounter if, for each of the following
-slopes, you start at the top-left corner and traverse the map all the way to
-the bottom:
-
-    Right 1, down 1.
-    Right 3, down 1. (This is the slope you already checked.)
-    Right 5, down 1.
-    Right 7, down 1.
-    Right 1, down 2.
-
-In the above example, these slopes would find 2, 7, 3, 4, and 2 tree(s)
-respectively; multiplied together, these produce the answer 336.
-
-What do you get if you multiply together the number of trees encountered on
-each of the listed slopes?
-*/
-
-use aoc20;
-use std::str::FromStr;
-use thiserror::Error;
-
-fn main() {
-    let mut input = aoc20::ProgramInput::new(PUZZLE, INPUT);
-    println!("Day 3: {}", PUZZLE);
-
-    let map: Map = input.to_str().parse().unwrap();
-
-    println!(
-        ":: The number of trees encountered is: {}",
-        map.traverse_trees(3, 1)
-    );
-
-    let angles: Vec<(usize, usize)> = vec![(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
-    println!(
-        ":: The product of all angle traversals is: {}",
-        angles
-            .iter()
-            .map(|(cols, rows)| map.traverse_trees(*cols, *rows))
-            .product::<usize>()
-    );
-}
-
-#[derive(Debug)]
-pub struct Map {
-    data: Vec<Vec<bool>>,
-}
-
-impl Map {
-    pub fn columns(&self) -> usize {
-        self.data[0].len()
-    }
-
-    pub fn rows(&self) -> usize {
-        self.data.len()
-    }
-
-    pub fn is_valid(&self) -> bool {
-        if self.data.is_empty() {
-            return false;
-        }
-        let cols = self.columns();
-        for row in &self.data {
-            if row.len() != cols {
-                return false;
-            }
-        }
-        true
-    }
-
-    pub fn traverse_trees(&self, cols: usize, rows: usize) -> usize {
-        let ncols = self.columns();
-        let nrows = self.rows();
-
-        let mut trees = 0;
-        let mut col = 0;
-        let mut row = 0;
-        while row < nrows {
-            if self.data[row][col] {
-                trees += 1;
-            }
-            col = (col + cols) % ncols;
-            row += rows;
-        }
-        trees
-    }
-}
-
-#[derive(Error, Debug)]
-pub enum MapError {
-    #[error("invalid input")]
-    InvalidInput { input: String },
-}
-
-impl FromStr for Map {
-    type Err = MapError;
-
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.is_empty() {
-            return Err(Self::Err::InvalidInput {
-                input: s.to_string(),
-            });
-        }
-
-        Ok(Self {
-            data: s
-                .trim()
-                .lines()
-                .map(|l| {
-                    l.trim()
-                        .chars()
-                        .map(|x| match x {
-                            '.' => false,
-                            _ => true,
-                        })
-                        .collect()
-                })
-                .collect(),
-        })
-    }
+fn main() -> ExitCode {
+    aoc20::finish(aoc20::run(&aoc20::day03::DAY))
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_traverse_trees() {
-        let map: Map = r"
-            ..##.........##.........##.........##.........##.........##.......
-            #...#...#..#...#...#..#...#...#..#...#...#..#...#...#..#...#...#..
-            .#....#..#..#....#..#..#....#..#..#....#..#..#....#..#..#....#..#.
-            ..#.#...#.#..#.#...#.#..#.#...#.#..#.#...#.#..#.#...#.#..#.#...#.#
-            .#...##..#..#...##..#..#...##..#..#...##..#..#...##..#..#...##..#.
-            ..#.##.......#.##.......#.##.......#.##.......#.##.......#.##.....
-            .#.#.#....#.#.#.#....#.#.#.#....#.#.#.#....#.#.#.#....#.#.#.#....#
-            .#........#.#........#.#........#.#........#.#........#.#........#
-            #.##...#...#.##...#...#.##...#...#.##...#...#.##...#...#.##...#...
-            #...##....##...##....##...##....##...##....##...##....##...##....#
-            .#..#...#.#.#..#...#.#.#..#...#.#.#..#...#.#.#..#...#.#.#..#...#.#
-        "
-        .parse()
-        .unwrap();
-
-        assert_eq!(map.traverse_trees(3, 1), 7);
-    }
-}
-
-const PUZZLE: &'static str = "Toboggan Trajectory";
-const INPUT: &'static str = r"
-...#...#..#....#..#...#..##..#.
-.#..#.....#.#............###...
-.#...###....#.............##..#
-...##...##....#.....##..#.##...
-.....###.#.###..##.#.##.......#
-#...##.....#..........#..#.#.#.
-......##.......##..#....#.#....
-....#.###.##..#.#..##.##....#.#
-.......#.......###.#.#.##.....#
-.........#.#....#..........#.#.
-.#...##.....##.........#..#....
-.##....#.#.#...##......#.......
-##.#.#..#....#....#....#...#.#.
-##....#.#..##......#....##...#.
-....#..#..##..#.###.......#.#..
-.....##....###...........#.#.##
-#.....##.........#....##......#
-........###.#..#....#....#.....
-...#.......#.##..#.###......#..
-...............#..#....#.##....
-..#..###..#.#..#.........##..#.
-####..#..####..................
-#...####...#.......#.#.#...#...
-......###.....#......#..#..#...
-#...#.....##.....#.#..##...#.#.
-#...........##.......#.........
-.#..#.........#.#..##....#.....
-........##...#................#
-........#.###.#.###.#.#.##..##.
-.#....##.....#...##.#..#.#.....
-..#..#.....###....##.#....#.#.#
-#......##.##...##..#.........#.
-#..#..#.....#.....#.........#..
-#....#.#...###.........#...#...
-.#.#.....##......#.#......#....
-..##......##...#.#.#.#.........
-..#......#.....##.###.#.#..#...
-....#..#.......#..#..#.....#...
-.#.#.....#...#..........#......
-#.#..#...........#.#.##.#...#.#
-..#.#....###...#...#.....#.#...
-....##.#.###....####.......#...
-.....##....#.......#..#..#....#
-...##..#.#.#.#......#......#...
-...##...#....#...#......###...#
-........#..#.#.....#.###.......
-..#..##.#....#.#.........#...#.
-.....#.####....#.##.........#..
-......#...#...#.....#......###.
-.##.....#....#..#.#....#.....#.
-...........#...#....##..#...#..
-.....#....#.....#...##..#...#.#
-.#...#.........#.......#...#..#
-...#..#...#........#......#....
-..#..#####.#.....#.#....#...#.#
-...#.......#.#....#...##..#..#.
-####..#.#.###.#.#..............
-.##........#...#.#....#..#.....
-..#..............#.#..##...#.##
-.###.#.....#.#.....##.#......##
-....###.....#...#...#.#..#.....
-....###.#.##.......#....#...#..
-#..#...#......##..#.....#.#...#
-....#.#.........#..............
-#.##.##...#..#.#.#.....#...#.##
-#...#...#......#...........##..
-#.#.#......#............#.#....
-.#.#..######...#.#.........#.##
-..#.#..#...#......#............
-....#.....#......##..#.....#...
-.##............#....##..#......
-.#.#.#...#.##.............###.#
-#.#...#...#.....#....#.#.#.....
-........#..#......##.##.#.....#
-.....#.....#.#####...#....#....
-.#...#......#.........#.#......
-...#...#..##.....##....#..#....
-....#....##..#.........#.......
-..#........##..#.#........#....
-...#...##...........#...#....#.
-.....##.........#..#....#..#.#.
-#..#....##..#...##.....#..##.#.
-..#.#.#.#...#...#.....#.#....#.
-.......#.###...#.#.......#.#...
-....#..#..#.###.#.....###..#.#.
-.#..##......#..#..#....#.####..
-..##...........#...#.........#.
-......#..#...#..........#......
-....#..........#......##...#...
-....#..#.##........#.#...##.#..
-#.##......#........##.#...#...#
-#..#....#.....###........##....
-...........##.....##..#....#.##
-..#....#..#..#......#.#.....#..
-#....#.##....#.....##.......#..
-.#.....#.#..............#.##..#
-.#..#..#...#...#....#.#.....#..
-...###...##.#...#..#........#..
-#...#.##.#.....#.#....#..#.....
-#.....###.#.......#.#..#.#..##.
-....#..#..##.......###.#...#...
-.#...####...............#.....#
-.#.##.#.....#.....#.#......##.#
-#...........#.##....###.##....#
-...............#..........#....
-.....#..#.##.###.#.............
-...##.............#.....#.#..#.
-....#.#...#.#..#..#..#....#....
-..#.......#..........#...#...#.
-...............#.#.#...###....#
-....#...#.##....#..##....#.....
-........#.#.##.........##.##.##
-#.....###.......#.#....#..#..##
-.#..#...#......#.#..##.......#.
-#.....#.#........#.##..#..#....
-.###..##.#.......#......###....
-.#...###.....#.....#....###...#
-........##.##......#.#....#...#
-.#....#..#.........#..##...##..
-.......#.......##.#..#..##.....
-#..##..##......#.#......#.##...
-..#..###..#...#....#..#...#....
-#.............#.####.........##
-..#..................#...#..#..
-..#......#........##.......#.#.
-.#.#.#.#..###.....#....#.#.....
-...#.##.###.......#....#.......
-................##...#.....#...
-..#.###.#...#.####....#..#..#..
-..#....###....##..#.#.........#
-.#..#.#.....#........#....##...
-.....#..#......#..#..##.#.#....
-.#..#.........##....##......#..
-.....#.#...#...#.#...#.#...#.#.
-..#..#...#...#...##.#..###.....
-..#..##......#..##.#...##......
-.......#..##....##.#......#..#.
-..#......#.#.....#.##....##....
-..#....#......#......##........
-....##.#.#....#.......#.##.....
-#.....#...###....#....#...#....
-............#.#..#...#...#..#..
-..##.............##....#.......
-.#.......#.##.#......#....##...
-...##............#....#..#...#.
-.##.####.....#.#..###.#....#.##
-....##.#........#..#...#.......
-...#...###.##...........##..#..
-..##..##....#...#..#..........#
-..#.........#.#...##..........#
-.......##....#.#...##.....#..#.
-.............#.....#.#.......#.
-#.......#..##..##...##.#.......
-..............#.....#.#..#...##
-........##..#.....#...#...#.#..
-###.#.................#........
-...#........#...#.#######..#..#
-...#.##...##.#.#..######...#...
-#.......#..#....#..#.##.....#..
-#..#....##....#.##.......#....#
-#...#..#.#.#...#..#.##..#......
-....#..##....#..#.#...........#
-.##..#.#.............###.......
-#....##......#..#..#.....###...
-..#..........#...###.#.........
-.####......#....#......#.#....#
-..#....#.#.#......#....#.......
-.....#.....#....#....#####....#
-.##..........#...#.###....#....
-....##.....##......#...#.#.....
-.#...#...#..#.#.#...#####......
-...#.##..####.##.##.......##...
-............#.......#..........
-.#..##.#..#####........#..#...#
-#......##..##..##.........##...
-....#....#.............#.#....#
-###..#.....#.....#.#...#..#.###
-#...#.......##......#....#.#.#.
-...#......#..#...#....#...###.#
-....#....##.......#....#......#
-............#......##.##.....#.
-...#.........#......#....##..##
-.....##....##...#..###...#..#..
-.......##.#..........#.##.##...
-....##...........#.#..#..#.##.#
-#...#..##.##.#....#....#.#.....
-...##.#.....#..#..#..###....##.
-#.##.#..#..#.#.............#...
-..#.#.............###.....#....
-...#..#....#..#.....#.#..#..#..
-...#.....##.#...........#..##.#
-.........#.#.##..#..#.#...#....
-...#..##..#...#...###.##.#..#..
-.#..##...##......##..##........
-......##....##.#.##.#.#........
-...#..................#.....#..
-.##................#.#..#..###.
-.##.##.....#................#..
-.....#.#..........#...#..#.#..#
-.............#......#..#.#..#..
-...#...##..#........#....#.....
-#......#........##.##...##.....
-##..#..##....#...#............#
-..##..##.##....##..##........#.
-...#....#.#.#.#....#.#...##....
-....#...##..##.#.##...#..#...#.
-#..#....##.#.....#.......#...##
-##.#....#.............#..#.....
-.##..#..#.#.....#.......#.#..#.
-.......#..#...##...#...###..#..
-..........#...#.#..##.....#...#
-..#....#...........#####....#..
-#....#..#.......##.............
-.........##..#####.......##....
-#..#..........#.....###...#..#.
-.#.#.#..#...#.......##...#####.
-.....#....#.###...#.......#....
-#.#.....##...###....###....#...
-.#.....#..#.#.#........#...#...
-.##.#.#.#......#....###....#...
-.#..##..####......###......#...
-......#.#.#.#.#...#...####.##..
-.#........##..#.....#....#....#
-.....###......##..#....#.......
-#.#.##...#.#......###..........
-........#.#...#..#......#....#.
-..##...##.........#.......#.#..
-..#.##....#...##.....#.###.....
-.........#..#.#....#....#.#.##.
-#.........#......#..#.......#..
-...#...##.......#.........#....
-............#......#...........
-##.....#.....#.#...#.....#.....
-..#.#...#..#...#.#...........#.
-#.#.#..#..#...##.#...#.#.....#.
-.#..###.#..##.#.....#.....#....
-##....##....#.......##..##.....
-.#..#...........###..........#.
-.#..#..#..........###..#.......
-#..###......#............##...#
-#......#........#..#..#..#.#...
-.......#.###...#.##............
-.##....#.......#.#...##.....#.#
-....#..#.#.......#.#...........
-##....#.###.#....#.#..##.#....#
-..#..#..#....#...#........##...
-...#...##....#..#.#...#..#.....
-......#..#......#....#.......#.
-#.#..............#...###...#..#
-...#....#..#..........#.#...#..
-#.....##..##.....#........#....
-.#...##..#.#..............#....
-##.#....#..##...#..#.####.#..#.
-.....#.......#.#.#.#..#.....###
-...#.##....#.#........##.......
-#...#.#...#.#..###..##.##...#.#
-###..............#.#.###.......
-...###..#.#..#....##...###.#...
-......##...........#...#..#...#
-.#..#.........##.......#..#...#
-.#.......###......##...#...#...
-.#......##...#........#.......#
-.#..#.....#.........#.#........
-#...#.#.....#...#..##.........#
-......##.#......##.#..##.#.....
-...............#.#..#....#....#
-#....#..#..#..#.#.....##...##..
-#.#......#.###......#..#...####
-.#.#..#...#...#.#..#.##.##.#.#.
-.....#.#...###...#.#.....##....
-...#..#.#..........##.#....#.#.
-...#..#.#.##.....###.##.#....#.
-..........#..###......#..#.#...
-###.....#..###..#...#..###.#...
-..#..#.....##.#.#..###.......#.
-....#....##........##..........
-.......#..........#...#......#.
-.#........#.#.#.#.#.......#....
-.#..#.......##..##....#.#...#..
-.#.#.#.......#..#..............
-#.#....#.#...#.#.#.....#.#...##
-.....#..........##..#.......#..
-.##......#.#....#.#.......#....
-..#.##....#.##.#...#...........
-...##......##..##.............#
-..........##.#.#..#..........#.
-.##....#..#..#.#....##.#...#.#.
-...........#....#.....#.#..#...
-.#.....#....##..#.........#....
-.....#.....#...#....#...#.###.#
-..#....#....#.....#...#......#.
-.....##..#.............#...#...
-........#..#.......#.#.......#.
-#...###..#.##.#...###...##..##.
-....##..#.......#...#.#........
-.#...#.#.##....####........#..#
-.#...#.#.####.##.#.............
-#..##...#....#...#.#.#.#.##..#.
-.#.......#........#.....###....
-#.#.....#....#..#....#..#....#.
-...#..#...#.....#.........##...
-.#....#......###...#....#.#.#..
-#.#........#......#...#....##..
-.....#..#......#..#..#......#..
-.#.....#..#.##.#.#.#...#......#
-##........#..#.#..#...#.####...
-..........##....#.#..#.#....#..
-#.##..#..#....#..#....##..#.#.#
-..#......#.......#...##..#.....
-##...#.........#......#......#.
-.#.....................#..#.##.
-.#.......#........#.#.#..##.#..
-..#..........#........#..##.#..
-.#...#...#.........##.#.#.#....
-....#....#.###.#....###....#.##
-....##......##........##.#.##..
-....#.#......#.##.#...#.##.....
-....#....#..#.#..###.#.#.......
-....#......#..#.#.......#..##..
-.....#..#.#.##.##..##.....#.#..
-...#....................##.....
-#.....#...##...#.#.............
-..#.#...#.#.#.....##..#....#...
-";
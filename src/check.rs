@@ -0,0 +1,109 @@
+//! Expected answers used by `--check`.
+//!
+//! The answers file contains one answer per line, keyed by day, part, and
+//! input name, separated by whitespace:
+//!
+//! ```text
+//! # day part input answer
+//! 1 1 default 1019571
+//! 1 2 default 100655544
+//! 7 1 inputs/alice.txt 112
+//! ```
+//!
+//! Empty lines and lines starting with `#` are ignored. The input name is
+//! `default` for the built-in input, `-` for stdin, or the path as it was
+//! given on the command line.
+
+use crate::input::read_file;
+use crate::{Answers, ParseError};
+
+use std::collections::HashMap;
+use std::error;
+use std::str::FromStr;
+
+#[derive(Debug, Default)]
+pub struct ExpectedAnswers {
+    answers: HashMap<(usize, usize, String), String>,
+}
+
+impl ExpectedAnswers {
+    pub fn from_file(path: &str) -> Result<Self, Box<dyn error::Error>> {
+        let answers = read_file(path)?
+            .parse()
+            .map_err(|err: ParseError| err.in_input(path))?;
+        Ok(answers)
+    }
+
+    /// Returns the expected answer for the part of the day with the given
+    /// input, if it is known.
+    pub fn get(&self, day: usize, part: usize, input: &str) -> Option<&str> {
+        self.answers
+            .get(&(day, part, input.to_string()))
+            .map(|x| x.as_str())
+    }
+
+    /// Returns the expected answers to both parts of the day with the given
+    /// input, where they are known.
+    pub fn answers(&self, day: usize, input: &str) -> Answers {
+        Answers {
+            part1: self.get(day, 1, input).map(|x| x.to_string()),
+            part2: self.get(day, 2, input).map(|x| x.to_string()),
+        }
+    }
+}
+
+impl FromStr for ExpectedAnswers {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut answers = HashMap::new();
+        for (i, text) in s.lines().enumerate() {
+            let line = text.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let malformed = || {
+                ParseError::at(text, line, "malformed answer")
+                    .at_line(i + 1)
+                    .with_hint("expected `<day> <part> <input> <answer>` with part 1 or 2")
+            };
+            let mut fields = line.splitn(4, char::is_whitespace);
+            let day = fields.next().and_then(|x| x.parse().ok());
+            let part = fields.next().and_then(|x| x.parse().ok());
+            let input = fields.next();
+            let answer = fields.next().map(|x| x.trim());
+            match (day, part, input, answer) {
+                (Some(day), Some(part @ 1..=2), Some(input), Some(answer)) => {
+                    answers.insert((day, part, input.to_string()), answer.to_string());
+                }
+                _ => return Err(malformed()),
+            }
+        }
+        Ok(Self { answers })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let expected: ExpectedAnswers = "
+            # day part input answer
+            1 1 default 1019571
+
+            7 2 inputs/alice.txt 32
+        "
+        .parse()
+        .unwrap();
+
+        assert_eq!(expected.get(1, 1, "default"), Some("1019571"));
+        assert_eq!(expected.get(1, 2, "default"), None);
+        assert_eq!(expected.get(7, 2, "inputs/alice.txt"), Some("32"));
+        assert!("1 3 default 5".parse::<ExpectedAnswers>().is_err());
+        assert!("1 1 default".parse::<ExpectedAnswers>().is_err());
+        let err = "  1 1 default".parse::<ExpectedAnswers>().unwrap_err();
+        assert_eq!((err.columns(), err.text()), (2..13, "  1 1 default"));
+    }
+}
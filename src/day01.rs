@@ -0,0 +1,756 @@
+#![doc = include_str!("day01.md")]
+
+use crate::rng::Rng;
+use crate::{Day, GenerateOptions, Solution, SolveOptions};
+use std::collections::{HashMap, HashSet};
+use std::io::{self, Write};
+use std::num::ParseIntError;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = ExpenseReport;
+    type Answer = i128;
+    type Error = ParseIntError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        Ok(ExpenseReport {
+            entries: parse_entries(input)?,
+            target: YEAR,
+            k: 3,
+        })
+    }
+
+    fn part1(report: &Self::Input) -> Option<i128> {
+        report.product(2)
+    }
+
+    fn part2(report: &Self::Input) -> Option<i128> {
+        report.product(report.k)
+    }
+}
+
+/// The entries of an expense report, together with the sum they must add up
+/// to and the number of entries to find for part two.
+#[derive(Debug, Clone)]
+pub struct ExpenseReport {
+    pub entries: Vec<i64>,
+    pub target: i64,
+    pub k: usize,
+}
+
+impl ExpenseReport {
+    /// Uses the target given with `--target` for both parts, and the number
+    /// of entries given with `--k` for part two.
+    pub fn configure(&mut self, options: &SolveOptions) {
+        self.target = options.target.unwrap_or(YEAR);
+        self.k = options.k.unwrap_or(3);
+    }
+
+    /// Returns the product of `k` distinct entries that sum to the target,
+    /// or `None` if there are no such entries or the product overflows.
+    pub fn product(&self, k: usize) -> Option<i128> {
+        find_k_sum(&self.entries, k, self.target)?
+            .into_iter()
+            .try_fold(1i128, |product, i| {
+                product.checked_mul(self.entries[i].into())
+            })
+    }
+}
+
+fn parse_entries(input: &str) -> Result<Vec<i64>, ParseIntError> {
+    input.lines().map(|x| x.parse()).collect()
+}
+
+/// Returns the indices of `k` distinct entries that sum to `target`, in
+/// increasing order, or `None` if there are none.
+///
+/// Pairs are found with a hash map and triples by walking two pointers
+/// through the sorted entries, so both take linear and quadratic time. Larger
+/// `k` meet in the middle: the sums of the lower half of a solution are
+/// stored and looked up from each sum of its upper half. Combinations that
+/// cannot reach the target are skipped, as are entries repeating the value of
+/// the previous one, but without such pruning this still takes about
+/// `n^(k/2)` time and memory for `n` entries.
+///
+/// The sums are computed in `i128`, so they cannot overflow.
+///
+/// ```
+/// use aoc20::day01::find_k_sum;
+///
+/// let entries = [1721, 979, 366, 299, 675, 1456];
+/// assert_eq!(find_k_sum(&entries, 2, 2020), Some(vec![0, 3]));
+/// assert_eq!(find_k_sum(&entries, 3, 2020), Some(vec![1, 2, 4]));
+/// assert_eq!(find_k_sum(&entries, 4, 2020), None);
+/// ```
+pub fn find_k_sum(entries: &[i64], k: usize, target: i64) -> Option<Vec<usize>> {
+    if k > entries.len() {
+        return None;
+    }
+    let target = i128::from(target);
+    match k {
+        0 => Some(Vec::new()).filter(|_| target == 0),
+        1 => entries
+            .iter()
+            .position(|&x| i128::from(x) == target)
+            .map(|i| vec![i]),
+        2 => two_sum(entries, target),
+        3 => three_sum(&Sorted::new(entries), target),
+        _ => meet_in_the_middle(&Sorted::new(entries), k, target),
+    }
+}
+
+fn two_sum(entries: &[i64], target: i128) -> Option<Vec<usize>> {
+    let mut seen = HashMap::new();
+    for (j, &x) in entries.iter().enumerate() {
+        let x = i128::from(x);
+        if let Some(&i) = seen.get(&(target - x)) {
+            return Some(vec![i, j]);
+        }
+        seen.entry(x).or_insert(j);
+    }
+    None
+}
+
+fn three_sum(sorted: &Sorted, target: i128) -> Option<Vec<usize>> {
+    let values = &sorted.values;
+    let n = values.len();
+    for first in 0..n - 2 {
+        let x = values[first];
+        if x + values[first + 1] + values[first + 2] > target {
+            break;
+        }
+        if x + values[n - 2] + values[n - 1] < target {
+            continue;
+        }
+        let (mut lo, mut hi) = (first + 1, n - 1);
+        while lo < hi {
+            let sum = x + values[lo] + values[hi];
+            if sum == target {
+                return Some(sorted.indices(&[first, lo, hi]));
+            } else if sum < target {
+                lo += 1;
+            } else {
+                hi -= 1;
+            }
+        }
+    }
+    None
+}
+
+/// The entries sorted by value, with their indices in the input.
+struct Sorted {
+    values: Vec<i128>,
+    indices: Vec<usize>,
+    /// The sum of the first `i` values at `prefix[i]`.
+    prefix: Vec<i128>,
+}
+
+impl Sorted {
+    fn new(entries: &[i64]) -> Self {
+        let mut order: Vec<usize> = (0..entries.len()).collect();
+        order.sort_unstable_by_key(|&i| entries[i]);
+        let values: Vec<i128> = order.iter().map(|&i| entries[i].into()).collect();
+        let mut prefix = vec![0];
+        for x in &values {
+            prefix.push(prefix.last().unwrap() + x);
+        }
+        Sorted {
+            values,
+            indices: order,
+            prefix,
+        }
+    }
+
+    /// Returns the sum of the values at positions `start..end`.
+    fn sum(&self, start: usize, end: usize) -> i128 {
+        self.prefix[end] - self.prefix[start]
+    }
+
+    /// Returns the first position in `start..end` with a value of at least
+    /// `x`, or `end` if there is none.
+    fn first_reaching(&self, start: usize, end: usize, x: i128) -> usize {
+        start + self.values[start..end].partition_point(|&y| y < x)
+    }
+
+    /// Returns the indices in the input of the sorted positions, in
+    /// increasing order.
+    fn indices(&self, positions: &[usize]) -> Vec<usize> {
+        let mut indices: Vec<_> = positions.iter().map(|&i| self.indices[i]).collect();
+        indices.sort_unstable();
+        indices
+    }
+}
+
+/// Splits a solution into its `k / 2` lowest sorted positions and the rest,
+/// and goes through the position where the upper half starts in order.
+///
+/// Before the upper halves that start at a position are looked up, the lower
+/// halves that end just before it are stored, so that the search stops as
+/// soon as it has seen all entries of some solution. Entries that repeat the
+/// value of the previous choice at the same place in a combination give the
+/// same sums and are skipped. For the start of an upper half, the reverse
+/// holds, and only the last `k - k / 2` entries with a value are tried.
+fn meet_in_the_middle(sorted: &Sorted, k: usize, target: i128) -> Option<Vec<usize>> {
+    let lower = k / 2;
+    let mut search = HalfSearch {
+        sorted,
+        target,
+        lower,
+        upper: k - lower,
+        sums: HashMap::new(),
+        positions: Vec::with_capacity(k),
+    };
+    let (values, n) = (&sorted.values, sorted.values.len());
+    for first in lower..=n - search.upper {
+        search.add_lower_halves(first - 1, 0, 0);
+        if first + search.upper < n && values[first + search.upper] == values[first] {
+            continue;
+        }
+        search.positions.push(first);
+        let found = search.upper_halves(first, first + 1, values[first]);
+        search.positions.pop();
+        if found.is_some() {
+            return found;
+        }
+    }
+    None
+}
+
+struct HalfSearch<'a> {
+    sorted: &'a Sorted,
+    target: i128,
+    lower: usize,
+    upper: usize,
+    /// A lower half for each sum of the lower halves stored so far.
+    sums: HashMap<i128, Vec<usize>>,
+    /// The positions of the combination being built.
+    positions: Vec<usize>,
+}
+
+impl HalfSearch<'_> {
+    /// Stores the lower halves that end at `last` and could be part of a
+    /// solution, choosing the positions before `last` from `start` on, after
+    /// those chosen so far, whose values sum to `sum`.
+    fn add_lower_halves(&mut self, last: usize, start: usize, sum: i128) {
+        let sorted = self.sorted;
+        let n = sorted.values.len();
+        let lowest_upper = sorted.sum(last + 1, last + 1 + self.upper);
+        let highest_upper = sorted.sum(n - self.upper, n);
+        let need = self.lower - 1 - self.positions.len();
+        if need == 0 {
+            let sum = sum + sorted.values[last];
+            if sum + lowest_upper <= self.target && sum + highest_upper >= self.target {
+                let positions = &self.positions;
+                self.sums.entry(sum).or_insert_with(|| {
+                    let mut half = positions.clone();
+                    half.push(last);
+                    half
+                });
+            }
+            return;
+        }
+        let rest = sorted.values[last];
+        let highest_rest = sorted.sum(last + 1 - need, last) + rest + highest_upper;
+        let from = sorted.first_reaching(start, last + 1 - need, self.target - sum - highest_rest);
+        for i in from..=last - need {
+            if i > start && sorted.values[i] == sorted.values[i - 1] {
+                continue;
+            }
+            let sum = sum + sorted.values[i];
+            if sum + sorted.sum(i + 1, i + need) + rest + lowest_upper > self.target {
+                break;
+            }
+            self.positions.push(i);
+            self.add_lower_halves(last, i + 1, sum);
+            self.positions.pop();
+        }
+    }
+
+    /// Returns the first solution whose upper half starts at `first` and
+    /// continues from `start` on, after the positions chosen so far, whose
+    /// values sum to `sum`.
+    fn upper_halves(&mut self, first: usize, start: usize, sum: i128) -> Option<Vec<usize>> {
+        let sorted = self.sorted;
+        let n = sorted.values.len();
+        let need = self.upper - self.positions.len();
+        if need == 0 {
+            let half = self.sums.get(&(self.target - sum))?;
+            return Some(sorted.indices(&[&half[..], &self.positions].concat()));
+        }
+        let lowest_lower = sorted.sum(0, self.lower);
+        let highest_lower = sorted.sum(first - self.lower, first);
+        let highest_rest = highest_lower + sorted.sum(n + 1 - need, n);
+        let from = sorted.first_reaching(start, n + 1 - need, self.target - sum - highest_rest);
+        for i in from..=n - need {
+            if i > start && sorted.values[i] == sorted.values[i - 1] {
+                continue;
+            }
+            let sum = sum + sorted.values[i];
+            if lowest_lower + sum + sorted.sum(i + 1, i + need) > self.target {
+                break;
+            }
+            self.positions.push(i);
+            let found = self.upper_halves(first, i + 1, sum);
+            self.positions.pop();
+            if found.is_some() {
+                return found;
+            }
+        }
+        None
+    }
+}
+
+/// A solution that looks up the missing entry in a hash set for every entry
+/// or pair of entries, which `Solver` is tested against.
+pub struct HashSolver;
+
+impl Solution for HashSolver {
+    type Input = Vec<i64>;
+    type Answer = i64;
+    type Error = ParseIntError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        parse_entries(input)
+    }
+
+    fn part1(report: &Self::Input) -> Option<i64> {
+        let entries: HashSet<_> = report.iter().collect();
+        report
+            .iter()
+            .find(|x| entries.contains(&(YEAR - *x)))
+            .map(|x| x * (YEAR - x))
+    }
+
+    fn part2(report: &Self::Input) -> Option<i64> {
+        let entries: HashSet<_> = report.iter().collect();
+        for x in report {
+            for y in report {
+                let z = YEAR - x - y;
+                if z > 0 && entries.contains(&z) {
+                    return Some(x * y * z);
+                }
+            }
+        }
+        None
+    }
+}
+
+/// The sum that the entries must add up to unless `--target` is given.
+const YEAR: i64 = 2020;
+
+/// Writes `options.count` entries (at least five), among which exactly one
+/// pair and one triple sum to 2020, even when an entry may be used twice.
+///
+/// The planted entries are below 1010, except for the larger one of the
+/// pair, and all other entries are above 1010, so that no two of them sum
+/// to 2020.
+pub fn generate(rng: &mut Rng, options: &GenerateOptions, out: &mut dyn Write) -> io::Result<()> {
+    const HALF: usize = YEAR as usize / 2;
+    let year = YEAR as usize;
+
+    let mut entries = loop {
+        let a = rng.range(1..HALF);
+        let c = rng.range(1..HALF);
+        let d = rng.range(1..HALF);
+        let e = match year.checked_sub(c + d) {
+            Some(e) if (1..HALF).contains(&e) => e,
+            _ => continue,
+        };
+        let planted = vec![a, year - a, c, d, e];
+        if count_sums(&planted, 2) == 1 && count_sums(&planted, 3) == 1 {
+            break planted;
+        }
+    };
+
+    let small: Vec<_> = entries.iter().copied().filter(|x| *x < HALF).collect();
+    let mut forbidden = Vec::new();
+    for (i, x) in small.iter().enumerate() {
+        forbidden.push(year - x);
+        for y in &small[i..] {
+            forbidden.extend(year.checked_sub(x + y));
+        }
+    }
+    while entries.len() < options.count {
+        let x = rng.range(HALF + 1..year);
+        if !forbidden.contains(&x) {
+            entries.push(x);
+        }
+    }
+
+    rng.shuffle(&mut entries);
+    for x in entries {
+        writeln!(out, "{}", x)?;
+    }
+    Ok(())
+}
+
+/// Returns the number of ways to pick `k` entries that sum to 2020, where
+/// an entry may be picked more than once.
+fn count_sums(entries: &[usize], k: usize) -> usize {
+    fn count(entries: &[usize], k: usize, target: usize) -> usize {
+        if k == 0 {
+            return (target == 0) as usize;
+        }
+        (0..entries.len())
+            .filter(|&i| entries[i] <= target)
+            .map(|i| count(&entries[i..], k - 1, target - entries[i]))
+            .sum()
+    }
+    count(entries, k, YEAR as usize)
+}
+
+/// Solves the input with `Solver`, with the options `--target` and `--k`.
+pub fn solve(input: &str, options: &SolveOptions) -> crate::SolveResult {
+    crate::solve_with::<Solver>(input, options, ExpenseReport::configure)
+}
+
+pub const DAY: Day = Day {
+    day: 1,
+    puzzle: PUZZLE,
+    description: DESCRIPTION,
+    input: INPUT,
+    solve,
+    stream: None,
+    generate,
+    options: &["target", "k"],
+};
+
+pub const PUZZLE: &str = "Report Repair";
+
+pub const DESCRIPTION: &str = include_str!("day01.md");
+
+pub const INPUT: &str = r"
+1388
+508
+1855
+1249
+1405
+1618
+1286
+1485
+1827
+1188
+1369
+1977
+910
+1398
+1912
+1575
+1713
+1771
+1506
+1056
+1890
+1065
+1591
+1438
+1155
+1275
+1622
+972
+1918
+1959
+1860
+1396
+1832
+1562
+1935
+1687
+1344
+1709
+1498
+1875
+1467
+1557
+1166
+1090
+1363
+1754
+987
+1548
+1334
+1315
+1300
+1043
+1417
+1040
+1955
+1440
+1635
+1519
+1305
+552
+1776
+1723
+1109
+1914
+981
+1886
+1607
+1639
+1582
+1444
+1627
+1157
+2008
+1554
+1781
+1847
+1415
+1915
+1416
+1431
+1579
+1193
+1921
+1971
+1360
+1631
+1972
+1988
+1813
+1378
+1505
+1973
+1585
+1091
+1853
+1531
+731
+1546
+1895
+1348
+1913
+1387
+1885
+1204
+1499
+1975
+1664
+1828
+1616
+1841
+1129
+137
+1676
+1694
+1928
+1354
+1814
+1228
+1588
+1642
+1261
+1446
+1903
+2003
+1751
+1083
+1829
+140
+1599
+1968
+1725
+1987
+1931
+1810
+1628
+2009
+1159
+1142
+1331
+1859
+1111
+1637
+1801
+1376
+1902
+1345
+1307
+1570
+1990
+1784
+1524
+1997
+1098
+1967
+1442
+1927
+1251
+1753
+1194
+1648
+1483
+1609
+1716
+1583
+1128
+1514
+1738
+1881
+1502
+1120
+1112
+433
+1033
+1208
+1982
+1544
+1169
+1306
+1690
+1590
+1938
+1177
+1819
+1568
+1666
+1682
+1844
+1783
+1774
+1688
+1925
+1471
+1203
+2007
+1769
+1323
+1370
+1689
+1268
+1868
+";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        let options = GenerateOptions {
+            count: 500,
+            ..Default::default()
+        };
+        for seed in 0..10 {
+            let input = DAY.generate_input(&mut Rng::new(seed), &options);
+            let report = Solver::parse(&input).unwrap();
+            let entries: Vec<usize> = report.entries.iter().map(|x| *x as usize).collect();
+            assert_eq!(entries.len(), 500);
+            assert_eq!((count_sums(&entries, 2), count_sums(&entries, 3)), (1, 1));
+        }
+    }
+
+    /// Checks `find_k_sum` against trying every set of `k` entries.
+    fn check_k_sum(entries: &[i64], k: usize, target: i64) {
+        let sum = |picked: &mut dyn Iterator<Item = usize>| -> i128 {
+            picked.map(|i| i128::from(entries[i])).sum()
+        };
+        let len = entries.len();
+        let exists = (0..1u32 << len).any(|set| {
+            let mut picked = (0..len).filter(|i| set & 1 << i != 0);
+            set.count_ones() as usize == k && sum(&mut picked) == target.into()
+        });
+        match find_k_sum(entries, k, target) {
+            Some(indices) => {
+                assert_eq!(indices.len(), k);
+                assert!(indices.windows(2).all(|pair| pair[0] < pair[1]));
+                assert_eq!(sum(&mut indices.into_iter()), target.into());
+            }
+            None => assert!(!exists, "{:?} k={} target={}", entries, k, target),
+        }
+    }
+
+    #[test]
+    fn test_find_k_sum() {
+        let mut rng = Rng::new(1);
+        for _ in 0..500 {
+            let len = rng.range(0..9);
+            let entries: Vec<i64> = (0..len).map(|_| rng.range(0..20) as i64 - 5).collect();
+            let target = rng.range(0..40) as i64 - 10;
+            for k in 0..=6 {
+                check_k_sum(&entries, k, target);
+            }
+        }
+    }
+
+    #[test]
+    fn test_find_k_sum_overflow() {
+        let (max, min) = (i64::MAX, i64::MIN);
+        let entries = [max, max - 1, 5, min, min + 1, max, 1, -1];
+        for &target in &[max, min, 0, 4, max - 2, min + 2] {
+            for k in 0..=6 {
+                check_k_sum(&entries, k, target);
+            }
+        }
+        assert_eq!(find_k_sum(&entries[..3], 2, max), None);
+
+        let report = Solver::parse("9223372036854775807\n9223372036854775806\n5").unwrap();
+        assert_eq!(Solver::part1(&report), None);
+        assert_eq!(Solver::part2(&report), None);
+    }
+
+    #[test]
+    fn test_find_k_sum_large() {
+        let options = GenerateOptions {
+            count: 50_000,
+            ..Default::default()
+        };
+        let input = DAY.generate_input(&mut Rng::new(5), &options);
+        let entries = Solver::parse(&input).unwrap().entries;
+        for &(k, target) in &[
+            (3, 2020),
+            (4, 2020),
+            (4, 3000),
+            (4, 8076),
+            (5, 7000),
+            (6, 12000),
+        ] {
+            let found = find_k_sum(&entries, k, target);
+            assert_eq!(found.is_some(), target != 2020 || k == 3);
+            if let Some(found) = found {
+                assert_eq!(found.len(), k);
+                assert_eq!(found.iter().map(|&i| entries[i]).sum::<i64>(), target);
+            }
+        }
+    }
+
+    #[test]
+    fn test_options() {
+        let input = "1721\n979\n366\n299\n675\n1456\n";
+        let options = SolveOptions {
+            target: Some(2319),
+            k: Some(4),
+            ..Default::default()
+        };
+        let mut report = Solver::parse(input).unwrap();
+        report.configure(&options);
+        assert_eq!(Solver::part1(&report), None);
+        assert_eq!(Solver::part2(&report), Some(979 * 366 * 299 * 675));
+
+        let options = SolveOptions {
+            k: Some(7),
+            ..Default::default()
+        };
+        let mut report = Solver::parse(input).unwrap();
+        report.configure(&options);
+        assert_eq!(Solver::part1(&report), Some(514579));
+        assert_eq!(Solver::part2(&report), None);
+    }
+
+    #[test]
+    fn test_hash_solver() {
+        let options = GenerateOptions {
+            count: 200,
+            ..Default::default()
+        };
+        let alternative = crate::solve::<HashSolver>;
+        crate::differential::assert_agree(&DAY, alternative, &options, 0..20);
+    }
+
+    #[test]
+    fn test_examples() {
+        crate::examples::assert_examples(&DAY);
+    }
+}
@@ -0,0 +1,1081 @@
+#![doc = include_str!("day05.md")]
+
+use crate::rng::Rng;
+use crate::{Day, GenerateOptions, InputError, ParseError, Solution, StreamingSolution};
+use std::io::{self, Write};
+use std::str::FromStr;
+use thiserror::Error;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Vec<usize>;
+    type Answer = usize;
+    type Error = ParseError;
+
+    /// Returns the seat IDs of all boarding passes.
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, x)| match x.parse::<SeatingPos>() {
+                Ok(pos) => Ok(pos.id()),
+                Err(err) => Err(err.at_line(i + 1)),
+            })
+            .collect()
+    }
+
+    /// Returns the highest seat ID.
+    fn part1(ids: &Self::Input) -> Option<usize> {
+        ids.iter().max().copied()
+    }
+
+    /// Returns the first free seat ID whose neighbours are both taken.
+    fn part2(ids: &Self::Input) -> Option<usize> {
+        let mut ids = ids.clone();
+        ids.sort_unstable();
+        ids.windows(2)
+            .find(|pair| pair[1] - pair[0] == 2)
+            .map(|pair| pair[0] + 1)
+    }
+}
+
+impl StreamingSolution for Solver {
+    /// Marks each seat as taken in a seating chart of all possible seat IDs,
+    /// so only the chart is kept in memory.
+    fn solve_lines<I>(lines: I) -> Result<[Option<usize>; 2], crate::Error>
+    where
+        I: Iterator<Item = Result<String, InputError>>,
+    {
+        let mut taken = [false; 128 * 8];
+        let mut highest = None;
+        for (i, line) in lines.enumerate() {
+            let pos: SeatingPos = line?
+                .parse()
+                .map_err(|err: ParseError| err.at_line(i + 1))?;
+            let id = pos.id();
+            taken[id] = true;
+            highest = highest.max(Some(id));
+        }
+        let free = (1..taken.len() - 1).find(|&id| !taken[id] && taken[id - 1] && taken[id + 1]);
+        Ok([highest, free])
+    }
+}
+
+/// A seat given by binary space partitioning, such as `FBFBBFFRLR`.
+///
+/// ```
+/// use aoc20::day05::SeatingPos;
+///
+/// let pos: SeatingPos = "FBFBBFFRLR".parse().unwrap();
+/// assert_eq!((pos.row(), pos.col()), (44, 5));
+/// assert_eq!(pos.id(), 357);
+/// ```
+#[derive(Debug)]
+pub struct SeatingPos {
+    row: usize,
+    col: usize,
+}
+
+impl SeatingPos {
+    pub fn row(&self) -> usize {
+        self.row
+    }
+
+    pub fn col(&self) -> usize {
+        self.col
+    }
+
+    pub fn id(&self) -> usize {
+        self.row * 8 + self.col
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.row < 128 && self.col < 8
+    }
+}
+
+impl FromStr for SeatingPos {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.chars().count() != 10 {
+            return Err(
+                ParseError::new(s, SeatingPosError::InvalidLength { value: s.into() })
+                    .with_hint("expected 10 letters, such as `FBFBBFFRLR`"),
+            );
+        }
+        let invalid = |i: usize, hint| {
+            let c = s.chars().nth(i).unwrap();
+            ParseError::new(s, SeatingPosError::InvalidLetter { c })
+                .with_columns(i..i + 1)
+                .with_hint(hint)
+        };
+        let (split, _) = s.char_indices().nth(7).unwrap();
+        let (row, col) = s.split_at(split);
+        Ok(Self {
+            row: binary_to_u8(row, 'F', 'B').map_err(|i| invalid(i, "expected F or B"))?,
+            col: binary_to_u8(col, 'L', 'R').map_err(|i| invalid(7 + i, "expected L or R"))?,
+        })
+    }
+}
+
+/// Returns the binary number spelled with the `zero` and `one` letters, or
+/// the index of the first other letter.
+fn binary_to_u8(s: &str, zero: char, one: char) -> Result<usize, usize> {
+    s.chars().enumerate().try_fold(0, |acc, (i, c)| match c {
+        c if c == zero => Ok(acc << 1),
+        c if c == one => Ok(acc << 1 | 1),
+        _ => Err(i),
+    })
+}
+
+#[derive(Error, Debug)]
+pub enum SeatingPosError {
+    #[error("Boarding pass has the wrong length: {value:?}")]
+    InvalidLength { value: String },
+    #[error("Boarding pass has an invalid letter: {c:?}")]
+    InvalidLetter { c: char },
+}
+
+impl From<SeatingPosError> for crate::Error {
+    fn from(err: SeatingPosError) -> Self {
+        crate::Error::parse(err)
+    }
+}
+
+/// Writes the boarding passes of a random range of consecutive seats, in
+/// random order, with one seat in the middle missing.
+///
+/// There are only 1024 seats, so the number of boarding passes is
+/// `options.count` limited to between 2 and 1023.
+pub fn generate(rng: &mut Rng, options: &GenerateOptions, out: &mut dyn Write) -> io::Result<()> {
+    let count = options.count.clamp(2, 1023);
+    let first = rng.range(0..1024 - count);
+    let missing = first + rng.range(1..count);
+    let mut ids: Vec<_> = (first..=first + count).filter(|x| *x != missing).collect();
+    rng.shuffle(&mut ids);
+    for id in ids {
+        let pass: String = (0..10)
+            .map(|i| {
+                let bit = id >> (9 - i) & 1 == 1;
+                match (i < 7, bit) {
+                    (true, false) => 'F',
+                    (true, true) => 'B',
+                    (false, false) => 'L',
+                    (false, true) => 'R',
+                }
+            })
+            .collect();
+        writeln!(out, "{}", pass)?;
+    }
+    Ok(())
+}
+
+pub const DAY: Day = Day {
+    day: 5,
+    puzzle: PUZZLE,
+    description: DESCRIPTION,
+    input: INPUT,
+    solve: crate::solve::<Solver>,
+    stream: Some(crate::solve_stream::<Solver>),
+    generate,
+    options: &[],
+};
+
+pub const PUZZLE: &str = "Binary Boarding";
+
+pub const DESCRIPTION: &str = include_str!("day05.md");
+
+pub const INPUT: &str = r"
+BFFFBBFRLR
+FBFFFBBLRL
+BFFFFFBRRR
+FBBBFFFLRR
+FFBFBFBRLL
+FBBFFBBLRR
+FBBFFFFRLL
+BFFFBBFRLL
+BBFBFBFRLL
+BFFFFFBRLL
+FFBFFFBRRR
+BBFFBFFRLR
+BBFFBBFLLL
+FBFBFBFLLL
+BBFFFFFLLR
+FFBBBFFRLL
+FFBFFBFRRR
+BFFFBBFLRL
+BFBBBBFLRR
+BFBFFBFLLL
+FFBFFBBLRL
+FFBFBFFRLR
+FFBFBBBLLR
+FFFBBFBLLR
+FFFBBBFLRL
+FBFBBBFLLL
+FBBFFFBLLR
+BFFFBBBRLL
+FFBBFBBRRL
+FBFFBBBLLR
+FBFBBFFRRL
+FBBBBFFLLL
+FFBFBFFLLR
+BFFFFBFLLR
+FBBBFBFLRR
+FBFFFFFRRR
+FFBFBBFRRL
+BFFFBBBLLR
+FBBFFBFRLL
+BFFFFBBRLL
+FBBBFFFRRL
+BBFBFFFLLR
+FBBFBBFRLR
+FFFBFFBRRR
+BFBBFBBLRL
+FBBBFFBLRR
+BBFFBBFLRR
+FFBBFBBLLL
+BFFBBBBRRL
+FBBFBFBRLL
+BFFFFFFRRL
+BBFBFBFLLL
+FBFFBFFLLR
+FBFBFBFRLR
+BFBFBBBRLR
+BBFFBBFLLR
+BFFBFBFRRR
+BFBBFBFLLL
+FBFBBFBRLR
+FBBFBFBLRL
+FBFFBFFLRR
+BFFFFFFRRR
+BFFFFBFRLL
+FBFBFFFLLR
+BFFFFBFLRL
+BBFFFFFRLL
+FBFBFFFLRR
+BFFBFBBLRL
+BFBFBBFLLR
+BFBFBBBLRL
+BFFBFFFRRL
+BFFFFFFLLL
+FFBBBBBLLR
+FBBBFFFLLL
+BFFFBBFRRL
+FFFBFBBRLL
+FFFBFFBRRL
+BFBFBFBRRR
+BBFFFBFLLR
+BFFFBFFLLL
+FBFBBFBLRR
+FFBBBBFLRR
+BFFBBBFLRR
+BBFFBBBLLR
+FFFBFBBRRL
+BBFFFBBLRR
+FFBFFFBRRL
+FFBFFFBRLR
+BFBFBFFRLL
+FBFFFBFLRL
+BBFFFFFRLR
+BFBBFFBRLR
+FBFBFBBRRR
+BFBFFFFLLR
+FFBFBFBLRR
+FFFBBBBRRL
+FBFFBBFRRR
+BFFBBBBLRR
+BFFBFFFLLR
+BFBFFBFLLR
+FBBBBBBRLR
+BFBFFFFLRL
+BBFFBFFLLL
+FBBBBBBLLR
+FFBBBBBRRL
+BFBFFFFLLL
+FBFBBBFRLL
+BFFBBBFLRL
+FBBFBBBLLL
+FBFBBFBRRR
+FFBBFFFRLL
+BBFFBBBLRL
+FBBFFFBRLL
+BBFBFFBRLR
+FBBBFFBLLR
+BFBFFFBRRL
+FBFFBBBRLL
+FBFBBBBRLL
+BFFFBFFRLR
+FFBBFBFRLR
+BFBFBFFLRR
+FFFBBFFLRR
+BFBFBBBLLL
+BFBFBFBRLR
+BFFFFBFLRR
+FFBBBFBRRR
+BFFFFBBLLR
+BBFFBFBLRR
+BFBFFFBRRR
+BFFFFBBRLR
+FBBBBBFRRR
+FBBFBBFLLR
+FFBBFBBRLL
+BFBBFFBLRR
+FBBBFFFLLR
+BFFFBFFLRL
+FBBBBFBLLR
+BFBBFBFLRR
+BFBBBFFLLL
+BFFBFBFRLL
+BFBBFBFRRL
+BFFBFFBLRR
+FFBFFFBLLL
+FFFBFBFLRL
+FBBBFBBLRR
+BFFFBBBLRR
+FBFFFFFRLR
+BBFFBBBLRR
+BFBFFBBLRR
+FBFFBBBRRR
+FBFFBFFLLL
+BFBBBBFRLL
+FBBBBBBRRL
+BFFBBBBLRL
+FFBFBBFLRR
+FFBBBFBLLL
+FBBFFFFRLR
+BBFBFBFRRR
+FBFBFFBLRL
+FFBBFBBLRL
+FBFBBFFLLL
+BFFFBFBLRR
+FBFBFFBLLR
+FBBBBBFLRL
+FFBFBFFRLL
+BBFFBBFRRR
+FFFBBFFLLR
+BFBBBFBLRR
+FFBBFFBLRL
+BFFBBBFRRL
+BFBBBBFRRL
+BBFFBFFRRL
+FFBFFBBRRR
+BFFBFBFLRR
+FBFBFFBRRR
+FBBFBBFLRL
+FBFFFFBLRL
+FBFFFBBLLL
+FFBFFBFRLR
+FBFFFBBRRL
+BFBBBFBLLL
+BFBFBFFLRL
+BFBFFBFRRR
+BBFFBBBRLL
+BBFFFBFLRL
+BBFFBFFRLL
+FBBFFBFLLL
+FBBBBFFLLR
+FFBFFFFLLR
+FFBBBBFLLR
+BFBBBBBRRR
+FFBBFBFRRR
+FFBFFBFLRL
+FBFBBFFRRR
+BFBBBFFRRR
+FBBFBBFRLL
+FBBFBBBRLR
+BFFFFFBRLR
+BFFBFBBRLL
+FBFFFBBLRR
+FFBFFFFRLL
+FBBBBFBRRL
+FBBBBFBRLR
+BFBBBBFLLL
+FBBBBBFRLL
+FBFBFBFLRL
+FFBFBBFLLL
+FBBBBFFRRL
+BFFBFBFLLL
+FFFBBBFLLL
+FBFBBBBLLL
+BFBBBBFRLR
+BBFBFFFRRR
+FBBBBBBLRL
+BFFFBFBLRL
+FFBBFBFLLR
+BFBBBFBLRL
+FBBBBBBRLL
+FFBBFFFRRR
+BFFFFBBRRR
+FBFFBBBLLL
+BBFBFFFLRL
+FFBFFFBLRR
+FFBBBFBLLR
+BFFBBFFRRR
+FBBFBFFLRL
+FFBBBBBLRR
+FBBBFBBLLL
+BBFBFBFRLR
+FBFBFBBLRR
+FBBBFBBRLR
+FBFBFBBLLR
+FFFBBFBRRR
+FBBBFFBRLL
+FFBBBBFLRL
+BBFFBFFRRR
+FBFBFBFLLR
+FBBBFBFRLR
+BFFBBBFRRR
+FFFBFBBLLR
+BBFBFBBLRR
+FBFFBBFRLR
+FFBBBFFLLR
+FFBBFBFLRL
+BFFBFFFLLL
+BFBFFBFRRL
+FBFBBBBRRL
+BFBBBBFLLR
+FBBFFBBRLL
+BFFBBFBRLR
+BFBFFFBLRR
+FFBBFFBLRR
+FBBFFFFRRL
+BFBBFBBLLR
+FBFFFBFRLR
+BFBBBBFLRL
+BFBBBBBRLL
+FFBBBBFRLR
+BBFBFFBLLR
+FBBFFFBLRR
+FBFFFFFLLL
+FBBFFBFRLR
+FBFFBBBRRL
+FFBFBFBRRL
+FFBBBBFLLL
+FFBFBBBRLR
+FBBFBFBRLR
+BBFFFBBLLR
+FBBFFBBLLL
+FFBBFFFLLL
+FBBBBBFLRR
+FFBBFFFLRR
+BFFBFFBLLR
+FBFFBFBRRR
+FFFBFFBRLR
+FBFFFFFLRR
+BFFFBFBRLL
+FBFBBFBRRL
+FFBFBBBLRL
+BFFFBBFLLR
+FBBFFBBRRR
+BFBBFBFRLL
+FBFFFFBRLL
+BBFFFFBLRR
+BFFBFBFLLR
+FFBFBBBRRL
+FBBFFFBRLR
+FFBBBBFRRR
+BFBBFFBLLR
+BFBFFFBLLL
+BFFFBBBRRR
+FBFBBBFRRR
+BFBFFBBRRL
+FBFBBFBLLR
+BFFFBBBRLR
+BFFFFBFLLL
+BFFBFFBRLR
+FBBFBFBRRR
+FFBBFBBRRR
+FBFFFBBLLR
+BFBBFBBRLR
+FFBBBBBRLR
+FBFFBBFRRL
+BFFBBBFLLR
+FBBBFFBLLL
+BFBFBFFRRR
+BFBFBBFRRR
+FBBFFBBLLR
+BFFFFFFLLR
+FBBBBFFRLR
+FBBBBBBLLL
+BFBBBBFRRR
+BFBBBFFRLR
+FFBFFBBRLL
+FFBBFBFLLL
+BFBFFFBLRL
+FFFBBBFLRR
+FBBFBFFRRL
+FFFBFBFLLL
+BFFBBFBLLL
+BBFFFFBLLL
+BFBBBBBRRL
+FBBFBFBLRR
+FBFFBFBRLL
+BBFBFBBLLL
+FFBBBFBLRR
+FBBBFFFRRR
+BFFBBBBLLL
+FBFBFBFRRR
+BFFFFFBLLL
+BFBFBFBLLR
+BBFFFFBRRL
+FBBBFBFLLL
+FFBFFFFLLL
+FBFBFFBLLL
+FBBBBBBRRR
+BFBFFBFLRR
+FFBFBFBLLL
+FFBFFBFRRL
+BFBFBBFRRL
+FFBBFBBLLR
+FBBBBBFLLR
+BFFBFFFRLL
+BBFFFBBLLL
+FBFFFFFLLR
+FFBBBFFRRR
+FBFFFBFRRR
+FFFBBBBLRL
+FBFBFBBRRL
+FBBFBFBLLL
+BFBFFFFLRR
+BBFFBFBLRL
+FFFBFBFRLR
+BFFBBFFLRL
+FFFBBFFRRL
+BFBFBFBRRL
+FFBBBFFLLL
+BBFFFBFLRR
+FFFBBBBLLR
+FFBBBFFRLR
+FFBFBBFLRL
+FFBBBFBLRL
+BFBFFFFRRL
+FBFBBFFLRR
+FBBBBBBLRR
+FBFBBBBRRR
+FFBFBFFLRR
+FBBFBFFRLR
+BFFBBBBRRR
+BFFFBFBLLL
+BFFBBFFLLL
+BFFFFBBLRL
+FFBBBBBLLL
+FBBBBFFRRR
+FFBBFFBRRL
+BBFBBFFLLL
+FFFBBFBRRL
+BFBFFFFRLR
+FBFFBFBLLL
+FFFBBFFLRL
+BBFFBFFLLR
+FBBFBFFLRR
+FBFFBBBRLR
+FBFFFFFRRL
+FFFBBFBRLL
+BFFBFFFLRL
+BFFBBFBLRR
+FFBFBFBLRL
+FBBFBFBLLR
+BBFFFFBLLR
+BFBFBFBLRL
+FFBBBFBRRL
+BBFFBFBLLR
+BBFFFFBRRR
+FFBFBFBRLR
+BBFBFFFRLR
+FBBBFBFRLL
+FFBBFBFRRL
+BBFBFBBLLR
+FFBBFFFLRL
+FFBFFBBLLL
+FBFBBFFLLR
+FFBFFFFLRR
+BFBBFFBRLL
+BFBBFBBLLL
+BBFBFFFRRL
+FBBBFBBRRL
+BBFBFBBRLR
+BBFBFFBRRL
+FBFBBBFLRL
+BFBFBFFRRL
+FFBBBBFRRL
+BFFFBBFRRR
+FFFBBBBRLL
+FBBBFFBRLR
+FBBFBBBRLL
+BFBFFBBLRL
+FFFBBBFLLR
+BFBBFBBRRR
+FFFBFBBLLL
+FFFBBBBRLR
+FBFFBFBLLR
+FBFBFBBRLR
+FFBBFFBRLR
+BBFFFFFLLL
+FFFBFFBRLL
+FFBBBBBRLL
+FBBFBFFLLR
+BBFFFBBLRL
+FBBBFBBLLR
+BFFFFFFLRL
+FFFBFBFRRR
+BFFFFFBLRL
+BFBBFBFLRL
+BFBBFFBRRL
+FBFBBFFRLL
+FFBFBFFRRL
+BFFFBBFLLL
+BBFFBFBRLR
+BFFBFBBLLR
+FBFBBBBLRL
+FBFFBFFRRR
+FBFFFBBRRR
+FBBFFBBLRL
+FBFBBBBRLR
+BFBBFFFRRR
+BFFBBFBRRL
+FBBFBFFRLL
+FFBBFFFRLR
+FFFBFBFLRR
+FFBBBBFRLL
+FBBFBBBLRL
+BBFFBBFRLR
+BBFFFBFLLL
+BFFFFBFRRL
+BFFBBFBLLR
+FFBFFFBLLR
+FFFBFBBLRR
+BFBFFBFLRL
+BFFBBFBRLL
+FBFFBBFRLL
+FFBBFBFLRR
+BBFFBFBRLL
+FBBBFFBRRL
+FBFBBBFLLR
+FFBBFFFLLR
+FBFFBBFLRR
+BFBFBFBLLL
+BBFBFFBLLL
+BFFFBBFLRR
+BFBBBFBRLL
+BFBFBBFRLL
+BBFBFFFLLL
+FBFFFFBLRR
+BFFFBFBLLR
+FFBBFFBLLL
+FBBBFFFLRL
+BFFBFFBRRL
+FBBFBFBRRL
+FBBBBBFRLR
+FBBBBFFLRL
+FBBBFBFRRL
+BFFFBBBRRL
+FBBFBFFRRR
+BFFFFBFRLR
+BBFBFFBLRR
+BFBBBBBRLR
+FBFFFBBRLR
+FBFFBBFLRL
+FBFFFFBLLR
+BFFBFBFRRL
+FFBFBBBLLL
+FBBFFBFLRR
+BFBFBFBLRR
+FFFBFBFRRL
+FFBBBBBLRL
+BFFBFFFRRR
+FFBFBFBLLR
+FFBFFBBRRL
+BFFBFFBRLL
+BBFFBFBLLL
+BFBBFBBRRL
+FFBFFBBRLR
+BFFFBFFRRR
+BFBBFFBLLL
+FBFFFBFLLR
+BFBFFFBLLR
+FFBBBFFLRL
+FFBFBBFRRR
+BFBBFFFRLR
+BFFFBFFLLR
+FBBFBBFRRL
+FBBFFBFLRL
+BFBBFBFLLR
+FFBBBFBRLR
+FBFBBFFLRL
+FBFBFBFRRL
+FBBBFBFLRL
+FBFBBFFRLR
+FFBFFBFLRR
+BFFBFBBRRL
+BFFBBBBRLL
+BFBFBBBRRL
+BFFFFBBRRL
+BFFFFBBLLL
+FBFFFBFLRR
+BFFFBBBLRL
+BBFFBBFLRL
+FFBBFBFRLL
+FBBBFFFRLR
+FBFFBFBRRL
+BFBBBBBLLL
+BBFFBFBRRR
+FBBBFFBLRL
+BFBBFFFLLL
+BBFFFFFRRR
+FFFBFFBLRR
+FBBBFBFLLR
+BBFFFBBRRR
+FBFFFBFLLL
+BBFFFBFRLR
+BFFBFBFRLR
+BFBBBFFRRL
+BFBFBBBRLL
+BFFBBFBLRL
+FFBFBBFLLR
+FBBFFFBLLL
+BBFBFBFLRL
+FFFBFBBRRR
+BBFFFFBRLL
+BFFBFFBLLL
+BFFFBBBLLL
+FFFBBFBLRR
+FBBFBBBLRR
+FBFFFBBRLL
+FFBFFFBLRL
+BBFFFBFRLL
+BFBFBFFRLR
+FBFBFBBRLL
+FBBBFFBRRR
+FBFBFFFRLR
+BFBBBFBLLR
+FBFFFBFRRL
+BFBBFFFLRL
+BFFBBFBRRR
+BBFBFFFRLL
+BFBBBFFLLR
+FBFFBBBLRR
+BFFBBBFRLL
+BFBBFFFRRL
+FFFBBBBLRR
+BBFFBBBRLR
+FBFBBBBLLR
+BFFFBFBRRR
+FBFBFFFLLL
+FFFBBBFRRR
+FFBFFBFLLL
+BBFFBBBRRR
+FFBFBBBRLL
+FBBBBFFLRR
+FBBFFFBRRL
+FBBBFBBRRR
+FBFBBFBLRL
+BBFFFBBRLL
+FBFBFFBLRR
+FFBFFFFRRL
+FBBBBFBLLL
+FFFBBFFRRR
+BFBFBBFLRL
+BFBFBBBRRR
+FBFFBBFLLR
+FBFBFBBLLL
+BFBFBBFRLR
+FFBBBFBRLL
+BBFBFBFLLR
+BFFFBFFRRL
+FFFBBBFRLL
+FBFFBFFLRL
+BFFFFFBLLR
+FFFBBFBRLR
+BBFBFFBLRL
+BFFBBFFRLL
+BFBBFFFLRR
+BFBBBBBLLR
+BFFBBFFLLR
+FFBFBFBRRR
+FFBBFFBLLR
+FBBBFBBRLL
+BFBBFFBLRL
+FBBFBBBRRL
+FBBBBFBLRL
+BFBBBFFLRL
+BFFFFFBLRR
+FBFBFFFRLL
+FBFBFFBRRL
+FBFFBFFRLL
+BFBFBFBRLL
+BFFBFBFLRL
+BFBBFFBRRR
+BBFFFFFLRL
+FBBBFBBLRL
+BFBFFBBRLL
+BBFFFBBRLR
+BFBFFBFRLL
+FFBFFFBRLL
+BFFBBBBLLR
+FBFFFFFLRL
+BFBBBFFRLL
+BFFBFBBLLL
+BBFFBBBLLL
+BFFBBFFLRR
+BFFBFFFRLR
+FBFBFFFRRL
+FBBFFBFRRL
+FBBFBBBRRR
+BFFFFFBRRL
+BFBBFBFRRR
+BBFFBFFLRL
+FBFBBFBRLL
+FBFBFBFLRR
+BBFFBBFRLL
+FFBBBFFRRL
+BFFBBFFRRL
+BFFBFBBRRR
+BBFFFFFRRL
+FFBBFBBRLR
+BBFBFBBRRR
+BFBFFFFRLL
+BFFBBFFRLR
+FFFBBFBLRL
+BBFFFFFLRR
+BFBFBBFLLL
+BFBBBBBLRR
+FFFBFBFLLR
+FBFFFFBLLL
+BFFBBBFLLL
+BFFBBBBRLR
+BFFFFFFLRR
+FFFBFBBLRL
+BFFFFBFRRR
+FBFFBBFLLL
+FBFFFBFRLL
+BBFBFFBRLL
+BBFFFFBLRL
+BBFFBFFLRR
+FBBBBFBRRR
+FBBFFFFLLL
+FFBBBBBRRR
+BFBFFBBLLR
+FBFFFFFRLL
+FFBFBFFRRR
+FFBFFBFLLR
+BFBFBBFLRR
+FBFBBBBLRR
+FBBFFFFLRR
+FFBFBBBLRR
+FBFFFFBRLR
+FBFBBBFLRR
+BFBFFFFRRR
+FBBFFFFLLR
+BBFBFBBLRL
+FFBBFFBRRR
+FFBFFBBLLR
+BFBFBFFLLR
+FBBFBBFLRR
+FBBFFFBLRL
+BFFBFBBLRR
+FFBBFFFRRL
+FBFFBFBRLR
+FBFFBFBLRL
+FFBFBBFRLR
+FFBFFFFLRL
+BBFFFFBRLR
+FBFFBFBLRR
+BFFBFFBLRL
+BFBFFBBRRR
+FBFFFFBRRL
+FBFBFBBLRL
+FFFBBBBRRR
+BBFFBBFRRL
+FBBFFBBRLR
+BFFFFFFRLR
+FBBFBBFRRR
+FBFBBBFRLR
+BFBFFFBRLR
+FBBFFBFRRR
+FFBBFBBLRR
+FFFBBFFRLR
+FFFBFBBRLR
+FBBFFFFLRL
+FBFBFFBRLL
+BFBBFFFLLR
+FBBFBBBLLR
+BFFFFFFRLL
+BBFFBBBRRL
+FBFBBFBLLL
+BBFBFBFLRR
+FFBFBFFLRL
+FFBFBBFRLL
+FBBBFFFRLL
+BBFFBFBRRL
+FBBBFBFRRR
+FFBFBFFLLL
+FBFFFFBRRR
+BFBFFBBLLL
+FFFBFBFRLL
+FBBFBBFLLL
+BBFFFBFRRL
+FFFBBFBLLL
+BBFBFBBRLL
+BFBBBFBRRR
+BFFFFBBLRR
+BFBFFFBRLL
+BFFFBFFLRR
+BFFBBBFRLR
+FBBFFFFRRR
+FBFFBBBLRL
+FFBBBFFLRR
+FFBFBBBRRR
+BFBBFFFRLL
+BFBFBBBLLR
+FFFBBFFRLL
+FBFBFBFRLL
+FBBBBFFRLL
+FFFBBBFRLR
+BFBFBBBLRR
+FBFFBFFRRL
+FFBFFBFRLL
+FBBFFFBRRR
+FBBFFBBRRL
+BBFFFBFRRR
+FFFBBBFRRL
+FFFBBFFLLL
+BFBBFBFRLR
+FBBBBBFLLL
+BFBFBFFLLL
+FBFBFFFRRR
+FBFFBFFRLR
+BFBBFBBLRR
+FFBBFFBRLL
+BFBBFBBRLL
+FBBBBBFRRL
+BFFFBFBRLR
+BFBBBBBLRL
+BFFBFFBRRR
+BBFBFBFRRL
+BFBFFBFRLR
+BFFBFFFLRR
+FBBBBFBRLL
+BBFBFFBRRR
+BFFFBFBRRL
+BFFBFBBRLR
+FBBBBFBLRR
+BFBFFBBRLR
+BFBBBFBRLR
+BBFFFBBRRL
+FBBFBFFLLL
+FFBFFFFRLR
+FBFBFFBRLR
+BFFFBFFRLL
+FBFBBBFRRL
+FBFBFFFLRL
+BBFBFFFLRR
+FFBFFFFRRR
+BFBBBFBRRL
+FFFBBBBLLL
+BBFBFBBRRL
+FFBFFBBLRR
+FBBFFBFLLR
+";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        for count in &[2, 100, 2000] {
+            let options = GenerateOptions {
+                count: *count,
+                ..Default::default()
+            };
+            let input = DAY.generate_input(&mut Rng::new(1), &options);
+            let ids = Solver::parse(&input).unwrap();
+            assert_eq!(ids.len(), (*count).min(1023));
+            let free = Solver::part2(&ids).unwrap();
+            assert!(!ids.contains(&free));
+        }
+    }
+
+    #[test]
+    fn test_examples() {
+        crate::examples::assert_examples(&DAY);
+    }
+
+    #[test]
+    fn test_seating_pos() {
+        struct Test {
+            pub input: String,
+            pub row: usize,
+            pub col: usize,
+            pub id: usize,
+        }
+
+        let tests = vec![
+            Test {
+                input: "FFFFFFFLLL".into(),
+                row: 0,
+                col: 0,
+                id: 0,
+            },
+            Test {
+                input: "FBFBBFFRLR".into(),
+                row: 44,
+                col: 5,
+                id: 357,
+            },
+            Test {
+                input: "BFFFBBFRRR".into(),
+                row: 70,
+                col: 7,
+                id: 567,
+            },
+            Test {
+                input: "FFFBBBFRRR".into(),
+                row: 14,
+                col: 7,
+                id: 119,
+            },
+            Test {
+                input: "BBFFBBFRLL".into(),
+                row: 102,
+                col: 4,
+                id: 820,
+            },
+        ];
+
+        for test in tests {
+            let pos: SeatingPos = test.input.parse().unwrap();
+            assert_eq!(pos.row, test.row, "row invalid");
+            assert_eq!(pos.col, test.col, "col invalid");
+            assert_eq!(pos.id(), test.id, "id invalid");
+        }
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let err = "FBFBBFFRLRR".parse::<SeatingPos>().unwrap_err();
+        assert_eq!(err.columns(), 0..11);
+
+        let err = "FBFBBFFRXR".parse::<SeatingPos>().unwrap_err();
+        assert_eq!(err.columns(), 8..9);
+        assert_eq!(err.hint(), Some("expected L or R"));
+
+        let err = Solver::parse("FBFBBFFRLR\nFBFBBFLRLR").unwrap_err();
+        assert_eq!((err.line(), err.columns()), (2, 6..7));
+    }
+
+    #[test]
+    fn test_solve_lines() {
+        let lines = vec!["FBFBBFFRLR", "FBFBBFFRLL", "FBFBBFFRRR"];
+        let answers = Solver::solve_lines(lines.into_iter().map(|x| Ok(x.to_string()))).unwrap();
+        assert_eq!(answers, [Some(359), Some(358)]);
+    }
+
+    #[test]
+    fn test_free_seat() {
+        assert_eq!(Solver::part2(&vec![3, 4, 5, 7, 8]), Some(6));
+        assert_eq!(Solver::part2(&vec![3, 4, 5]), None);
+    }
+}
@@ -0,0 +1,189 @@
+//! Plain text rendering of the puzzle descriptions for `--describe`.
+
+use std::env;
+
+/// Width used when the terminal width is unknown.
+pub(crate) const DEFAULT_WIDTH: usize = 80;
+
+const PART_TWO: &str = "## Part Two";
+
+/// Returns the width of the terminal, which the `COLUMNS` environment
+/// variable overrides, or `DEFAULT_WIDTH` if stdout is not a terminal.
+pub(crate) fn terminal_width() -> usize {
+    env::var("COLUMNS")
+        .ok()
+        .and_then(|x| x.parse().ok())
+        .filter(|&x| x > 0)
+        .or_else(tty_width)
+        .unwrap_or(DEFAULT_WIDTH)
+}
+
+/// Asks the terminal on stdout for its width.
+#[cfg(unix)]
+fn tty_width() -> Option<usize> {
+    // SAFETY: `winsize` is plain data, and TIOCGWINSZ only writes to it.
+    let mut size: libc::winsize = unsafe { std::mem::zeroed() };
+    let ok = unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) } == 0;
+    Some(usize::from(size.ws_col)).filter(|&x| ok && x > 0)
+}
+
+#[cfg(not(unix))]
+fn tty_width() -> Option<usize> {
+    None
+}
+
+/// Renders the Markdown description of a puzzle as plain text wrapped to
+/// `width` columns, with only the section of the given part, if any.
+///
+/// Paragraphs and list items are wrapped, headings lose their `#`, example
+/// blocks are indented by four spaces and never wrapped, and the example
+/// annotations are left out.
+pub(crate) fn describe(description: &str, part: Option<usize>, width: usize) -> String {
+    let text = match (part, description.find(PART_TWO)) {
+        (Some(1), Some(i)) => &description[..i],
+        (Some(2), Some(i)) => &description[i..],
+        _ => description,
+    };
+
+    let mut out = String::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut in_block = false;
+    for line in text.lines() {
+        if line.starts_with("```") {
+            flush(&mut out, &mut paragraph, width);
+            in_block = !in_block;
+        } else if in_block {
+            out.push_str(format!("    {}", line).trim_end());
+            out.push('\n');
+        } else if line.starts_with("<!--") {
+            continue;
+        } else if line.trim().is_empty() {
+            flush(&mut out, &mut paragraph, width);
+            out.push('\n');
+        } else if line.starts_with('#') {
+            flush(&mut out, &mut paragraph, width);
+            out += line.trim_start_matches('#').trim();
+            out.push('\n');
+        } else {
+            if line.starts_with("- ") {
+                flush(&mut out, &mut paragraph, width);
+            }
+            paragraph.push(line.trim());
+        }
+    }
+    flush(&mut out, &mut paragraph, width);
+
+    // Blank lines may pile up where annotations were left out.
+    while out.contains("\n\n\n") {
+        out = out.replace("\n\n\n", "\n\n");
+    }
+    out.trim_matches('\n').to_string() + "\n"
+}
+
+/// Appends the wrapped paragraph or list item to `out` and clears it.
+fn flush(out: &mut String, paragraph: &mut Vec<&str>, width: usize) {
+    if paragraph.is_empty() {
+        return;
+    }
+    let text = paragraph.join(" ");
+    let (first, rest, text) = match text.strip_prefix("- ") {
+        Some(item) => ("- ", "  ", item.to_string()),
+        None => ("", "", text),
+    };
+    for (i, line) in wrap(&text, width.saturating_sub(first.len()))
+        .iter()
+        .enumerate()
+    {
+        *out += if i == 0 { first } else { rest };
+        *out += line;
+        out.push('\n');
+    }
+    paragraph.clear();
+}
+
+/// Splits the text into lines of at most `width` characters at spaces,
+/// except for words that are longer than that.
+fn wrap(text: &str, width: usize) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut line = String::new();
+    for word in text.split_whitespace() {
+        if !line.is_empty() && line.chars().count() + 1 + word.chars().count() > width {
+            lines.push(line);
+            line = String::new();
+        }
+        if !line.is_empty() {
+            line.push(' ');
+        }
+        line += word;
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DESCRIPTION: &str = "# Day 1: Test
+
+Add up all the numbers in the
+list below, one by one.
+
+<!-- example: part1=3 -->
+```text
+1
+2
+```
+
+- The first item is
+  long enough to wrap.
+- Short.
+
+## Part Two
+
+Multiply them.
+";
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(
+            describe(DESCRIPTION, None, 20),
+            "Day 1: Test
+
+Add up all the
+numbers in the list
+below, one by one.
+
+    1
+    2
+
+- The first item is
+  long enough to
+  wrap.
+- Short.
+
+Part Two
+
+Multiply them.
+"
+        );
+    }
+
+    #[test]
+    fn test_describe_part() {
+        let part1 = describe(DESCRIPTION, Some(1), 80);
+        assert!(part1.starts_with("Day 1: Test\n"));
+        assert!(part1.ends_with("- Short.\n"));
+        assert_eq!(
+            describe(DESCRIPTION, Some(2), 80),
+            "Part Two\n\nMultiply them.\n"
+        );
+    }
+
+    #[test]
+    fn test_wrap_long_word() {
+        assert_eq!(wrap("a verylongword b", 4), vec!["a", "verylongword", "b"]);
+    }
+}
@@ -0,0 +1,167 @@
+//! Differential testing of alternative solutions against a day's solution.
+//!
+//! Both solutions are run on random inputs from the day's generator, and the
+//! first input on which their answers differ is shrunk by removing lines for
+//! as long as they still differ, leaving a small input to debug with.
+
+use crate::rng::Rng;
+use crate::{Answers, Day, GenerateOptions, SolveOptions, SolveResult};
+
+use std::fmt;
+use std::ops::Range;
+
+/// A function that solves a puzzle, such as `crate::solve::<S>`.
+pub type SolveFn = fn(&str, &SolveOptions) -> SolveResult;
+
+/// An input on which two solutions give different answers or errors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disagreement {
+    /// The seed of the generated input that was shrunk to `input`.
+    pub seed: u64,
+    pub input: String,
+    pub reference: Result<Answers, String>,
+    pub alternative: Result<Answers, String>,
+}
+
+impl fmt::Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "solutions disagree on input shrunk from seed {}:",
+            self.seed
+        )?;
+        writeln!(f, "{}", self.input)?;
+        writeln!(
+            f,
+            ":: Reference gives {}",
+            outcome_to_string(&self.reference)
+        )?;
+        write!(
+            f,
+            ":: Alternative gives {}",
+            outcome_to_string(&self.alternative)
+        )
+    }
+}
+
+fn outcome_to_string(outcome: &Result<Answers, String>) -> String {
+    match outcome {
+        Ok(answers) => format!(
+            "{} and {}",
+            answers.part1.as_deref().unwrap_or("no answer"),
+            answers.part2.as_deref().unwrap_or("no answer")
+        ),
+        Err(err) => format!("error: {}", err),
+    }
+}
+
+fn outcome(solve: SolveFn, input: &str) -> Result<Answers, String> {
+    solve(input, &SolveOptions::default())
+        .map(|(answers, _)| answers)
+        .map_err(|err| err.to_string())
+}
+
+/// Returns the first generated input on which the alternative solution
+/// disagrees with the day's solution, shrunk as far as possible, or `None`
+/// if they agree on the inputs of all seeds.
+///
+/// Two solutions agree if they give the same answers or both fail.
+pub fn find_disagreement(
+    day: &Day,
+    alternative: SolveFn,
+    options: &GenerateOptions,
+    seeds: Range<u64>,
+) -> Option<Disagreement> {
+    let disagree = |input: &str| match (outcome(day.solve, input), outcome(alternative, input)) {
+        (Ok(a), Ok(b)) => a != b,
+        (a, b) => a.is_ok() != b.is_ok(),
+    };
+
+    for seed in seeds {
+        let input = day.generate_input(&mut Rng::new(seed), options);
+        if disagree(&input) {
+            let input = shrink(&input, disagree);
+            return Some(Disagreement {
+                seed,
+                reference: outcome(day.solve, &input),
+                alternative: outcome(alternative, &input),
+                input,
+            });
+        }
+    }
+    None
+}
+
+/// Panics with the disagreement if the solutions disagree on any input.
+pub fn assert_agree(day: &Day, alternative: SolveFn, options: &GenerateOptions, seeds: Range<u64>) {
+    if let Some(disagreement) = find_disagreement(day, alternative, options, seeds) {
+        panic!("{}", disagreement);
+    }
+}
+
+/// Returns the input with as many lines removed as possible while `fails`
+/// still returns true for it, which it must for the input itself.
+///
+/// Chunks of lines are removed, starting with half of the input and halving
+/// the chunk size whenever no chunk can be removed.
+pub fn shrink<F: Fn(&str) -> bool>(input: &str, fails: F) -> String {
+    let mut lines: Vec<&str> = input.lines().collect();
+    let mut chunk = lines.len() / 2;
+    while chunk > 0 {
+        let mut removed = false;
+        let mut i = 0;
+        while i < lines.len() {
+            let end = (i + chunk).min(lines.len());
+            let candidate: Vec<&str> = lines[..i].iter().chain(&lines[end..]).copied().collect();
+            if fails(&candidate.join("\n")) {
+                lines = candidate;
+                removed = true;
+            } else {
+                i += chunk;
+            }
+        }
+        if !removed {
+            chunk /= 2;
+        }
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day05;
+
+    /// Solves day 5, except that part 1 is the lowest seat ID instead of
+    /// the highest if there are at least three boarding passes.
+    fn wrong(input: &str, options: &SolveOptions) -> SolveResult {
+        let (mut answers, timings) = crate::solve::<day05::Solver>(input, options)?;
+        let ids = <day05::Solver as crate::Solution>::parse(input)?;
+        if ids.len() >= 3 {
+            answers.part1 = ids.iter().min().map(|x| x.to_string());
+        }
+        Ok((answers, timings))
+    }
+
+    #[test]
+    fn test_shrink() {
+        let input = (1..=100)
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let fails = |s: &str| s.lines().any(|x| x == "42") && s.lines().any(|x| x == "77");
+        assert_eq!(shrink(&input, fails), "42\n77");
+    }
+
+    #[test]
+    fn test_find_disagreement() {
+        let options = GenerateOptions::default();
+        let disagreement = find_disagreement(&day05::DAY, wrong, &options, 0..10).unwrap();
+        assert_eq!(disagreement.seed, 0);
+        assert_eq!(disagreement.input.lines().count(), 3);
+        assert_ne!(disagreement.reference, disagreement.alternative);
+
+        let same = day05::DAY.solve;
+        assert_eq!(find_disagreement(&day05::DAY, same, &options, 0..10), None);
+    }
+}
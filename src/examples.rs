@@ -0,0 +1,208 @@
+//! Worked examples from the puzzle descriptions, which are checked as tests.
+//!
+//! An example is a fenced block in the description of a day (`src/dayNN.md`),
+//! preceded by an annotation with the expected answers, which rustdoc does not
+//! display:
+//!
+//! ````text
+//! <!-- example: part1=4 part2=32 -->
+//! ```text
+//! light red bags contain 1 bright white bag, 2 muted yellow bags.
+//! ...
+//! ```
+//! ````
+//!
+//! Only the parts that are given are checked, and `none` means that there is
+//! no answer. If the block explains its input line by line, such as
+//! `BFFFBBFRRR: row 70, column 7, seat ID 567.`, then `until=":"` cuts each
+//! line at the first `:`, leaving only the input. Values containing spaces are
+//! quoted, as in `until=" is "`.
+
+use crate::{Answers, Day, ParseError, SolveOptions};
+
+/// A worked example and the answers it is expected to have.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    /// The 1-based line number of the annotation.
+    pub line: usize,
+    pub input: String,
+    /// The expected answers, each of which is only checked if present.
+    pub expected: Vec<(usize, Option<String>)>,
+}
+
+impl Example {
+    /// Returns the parts where the answers differ from the expected ones,
+    /// together with the expected answer.
+    pub fn mismatches(&self, answers: &Answers) -> Vec<(usize, Option<&str>)> {
+        self.expected
+            .iter()
+            .filter(|(part, want)| answers.part(*part) != want.as_deref())
+            .map(|(part, want)| (*part, want.as_deref()))
+            .collect()
+    }
+}
+
+const ANNOTATION: &str = "<!-- example:";
+
+/// Returns the annotated examples in the text, which is usually the
+/// description of a day.
+pub fn examples(text: &str) -> Result<Vec<Example>, ParseError> {
+    let mut examples = Vec::new();
+    let mut lines = text.lines().enumerate();
+    while let Some((i, line)) = lines.next() {
+        let annotation = match line.trim().strip_prefix(ANNOTATION) {
+            Some(x) => x,
+            None => continue,
+        };
+        let invalid = |part: &str, hint: &str| {
+            ParseError::at(line, part, "invalid example annotation")
+                .with_hint(hint)
+                .at_line(i + 1)
+        };
+        let fields = annotation
+            .strip_suffix("-->")
+            .ok_or_else(|| invalid(annotation, "expected `-->` at the end"))?;
+
+        let mut example = Example {
+            line: i + 1,
+            input: String::new(),
+            expected: Vec::new(),
+        };
+        let mut until = None;
+        for (key, value) in split_fields(fields).map_err(|x| invalid(x, "expected key=value"))? {
+            let answer = match value {
+                "none" => None,
+                _ => Some(value.to_string()),
+            };
+            match key {
+                "part1" => example.expected.push((1, answer)),
+                "part2" => example.expected.push((2, answer)),
+                "until" => until = Some(value),
+                _ => return Err(invalid(key, "expected part1, part2, or until")),
+            }
+        }
+
+        match lines.find(|(_, x)| !x.trim().is_empty()) {
+            Some((_, fence)) if fence.trim_start().starts_with("```") => {}
+            _ => {
+                return Err(invalid(
+                    line,
+                    "expected a fenced block after the annotation",
+                ))
+            }
+        }
+        let mut input = Vec::new();
+        for (_, line) in lines
+            .by_ref()
+            .take_while(|(_, x)| !x.trim_start().starts_with("```"))
+        {
+            match until.and_then(|x| line.find(x)) {
+                Some(end) => input.push(&line[..end]),
+                None => input.push(line),
+            }
+        }
+        example.input = input.join("\n");
+        examples.push(example);
+    }
+    Ok(examples)
+}
+
+/// Splits `key=value` fields separated by whitespace, returning the first
+/// field that is malformed on error.
+fn split_fields(s: &str) -> Result<Vec<(&str, &str)>, &str> {
+    let mut fields = Vec::new();
+    let mut rest = s.trim_start();
+    while !rest.is_empty() {
+        let (key, value) = rest.split_once('=').ok_or(rest)?;
+        let (value, next) = match value.strip_prefix('"') {
+            Some(quoted) => {
+                let end = quoted.find('"').ok_or(rest)?;
+                (&quoted[..end], &quoted[end + 1..])
+            }
+            None => value.split_at(value.find(char::is_whitespace).unwrap_or(value.len())),
+        };
+        if key.is_empty() || key.contains(char::is_whitespace) {
+            return Err(rest);
+        }
+        fields.push((key, value));
+        rest = next.trim_start();
+    }
+    Ok(fields)
+}
+
+/// Solves each annotated example in the description of the day, and panics
+/// if any answer differs or if there are no examples.
+pub fn assert_examples(day: &Day) {
+    let examples = examples(day.description).unwrap_or_else(|err| panic!("{}", err));
+    assert!(!examples.is_empty(), "day {} has no examples", day.day);
+    for example in examples {
+        let (answers, _) = (day.solve)(&example.input, &SolveOptions::default())
+            .unwrap_or_else(|err| panic!("example at line {}: {}", example.line, err));
+        if let Some(&(part, want)) = example.mismatches(&answers).first() {
+            panic!(
+                "example at line {} of day {}: part {} is {:?}, expected {:?}",
+                example.line,
+                day.day,
+                part,
+                answers.part(part),
+                want
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_examples() {
+        let text = r#"
+Some text.
+
+<!-- example: part1=2 part2=none -->
+```text
+1-3 a: abcde is valid
+2-9 c: ccccccccc
+```
+
+<!-- example: until=" is " part2=1 -->
+
+```text
+1-3 a: abcde is valid
+```
+"#;
+        let examples = examples(text).unwrap();
+        assert_eq!(
+            examples,
+            vec![
+                Example {
+                    line: 4,
+                    input: "1-3 a: abcde is valid\n2-9 c: ccccccccc".into(),
+                    expected: vec![(1, Some("2".into())), (2, None)],
+                },
+                Example {
+                    line: 10,
+                    input: "1-3 a: abcde".into(),
+                    expected: vec![(2, Some("1".into()))],
+                },
+            ]
+        );
+
+        let answers = Answers {
+            part1: Some("2".into()),
+            part2: Some("1".into()),
+        };
+        assert_eq!(examples[0].mismatches(&answers), vec![(2, None)]);
+        assert!(examples[1].mismatches(&answers).is_empty());
+    }
+
+    #[test]
+    fn test_invalid_annotations() {
+        let err = examples("<!-- example: part3=1 -->\n```\n```").unwrap_err();
+        assert_eq!((err.line(), err.columns()), (1, 14..19));
+        assert!(examples("<!-- example: part1=1\n```\n```").is_err());
+        assert!(examples("<!-- example: part1 -->\n```\n```").is_err());
+        assert!(examples("<!-- example: part1=1 -->\ninput").is_err());
+    }
+}
@@ -0,0 +1,147 @@
+//! The errors that stop a run of a solution, and the exit status of each.
+//!
+//! | Status | Meaning                                                      |
+//! |--------|--------------------------------------------------------------|
+//! | 0      | All parts were solved, and match the answers with `--check`  |
+//! | 1      | The input cannot be read, or any other error, such as a bad  |
+//! |        | command line or answers that differ with `--check`           |
+//! | 2      | The input cannot be parsed                                   |
+//! | 3      | A part has no answer for the input                           |
+//!
+//! When several days or inputs are run, the status is that of the first
+//! error.
+
+use crate::{InputError, ParseError};
+
+use std::error;
+use std::io;
+use std::num::ParseIntError;
+use std::process::ExitCode;
+use thiserror::Error;
+
+/// An error that stops a run of a solution.
+///
+/// Each day converts its own parse errors into `Error::Parse`, so that they
+/// can be returned with `?`.
+#[derive(Error, Debug)]
+pub enum Error {
+    /// The input cannot be read.
+    #[error(transparent)]
+    Input(#[from] InputError),
+
+    /// The input cannot be parsed; this is usually a `ParseError`.
+    #[error("{}", parse_message(.0.as_ref()))]
+    Parse(Box<dyn error::Error>),
+
+    /// The solution found no answer to the part for the input.
+    #[error("there is no answer {part} for day {day}")]
+    NoAnswer { day: usize, part: usize },
+
+    /// Some answers differ from the expected answers given with `--check`.
+    #[error("some answers differ from the answers file")]
+    CheckFailed,
+
+    #[error("{0}")]
+    Other(Box<dyn error::Error>),
+}
+
+impl Error {
+    /// Returns a parse error with the error of a parser.
+    pub fn parse<E: Into<Box<dyn error::Error>>>(err: E) -> Self {
+        Error::Parse(err.into())
+    }
+
+    /// Returns the exit status of the program for the error; see the module
+    /// documentation.
+    pub fn exit_code(&self) -> u8 {
+        match self {
+            Error::Parse(_) => 2,
+            Error::NoAnswer { .. } => 3,
+            Error::Input(_) | Error::CheckFailed | Error::Other(_) => 1,
+        }
+    }
+}
+
+fn parse_message(err: &(dyn error::Error + 'static)) -> String {
+    match err.downcast_ref::<ParseError>() {
+        Some(err) => err.to_string(),
+        None => format!("cannot parse input: {}", err),
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(err: ParseError) -> Self {
+        Error::parse(err)
+    }
+}
+
+/// Day 1 parses its input with the standard library.
+impl From<ParseIntError> for Error {
+    fn from(err: ParseIntError) -> Self {
+        Error::parse(err)
+    }
+}
+
+impl From<Box<dyn error::Error>> for Error {
+    fn from(err: Box<dyn error::Error>) -> Self {
+        Error::Other(err)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Other(err.into())
+    }
+}
+
+impl From<String> for Error {
+    fn from(err: String) -> Self {
+        Error::Other(err.into())
+    }
+}
+
+impl From<&str> for Error {
+    fn from(err: &str) -> Self {
+        Error::Other(err.into())
+    }
+}
+
+/// Prints the error, if any, and returns the exit status for the result of
+/// running a program.
+///
+/// This is what the `main` function of each binary returns, since returning
+/// the `Result` itself would always exit with status 1.
+pub fn finish(result: Result<(), Error>) -> ExitCode {
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::from(err.exit_code())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_codes() {
+        let input = InputError::NotFound { path: "x".into() };
+        assert_eq!(Error::from(input).exit_code(), 1);
+        assert_eq!(Error::from("bad option").exit_code(), 1);
+
+        let err = Error::from(ParseError::new("abc", "invalid"));
+        assert_eq!(err.exit_code(), 2);
+        assert!(err.to_string().starts_with("invalid\n"));
+        let err = Error::parse("x".parse::<u8>().unwrap_err());
+        assert_eq!(
+            err.to_string(),
+            "cannot parse input: invalid digit found in string"
+        );
+
+        let err = Error::NoAnswer { day: 5, part: 2 };
+        assert_eq!(err.exit_code(), 3);
+        assert_eq!(err.to_string(), "there is no answer 2 for day 5");
+    }
+}
@@ -0,0 +1,391 @@
+//! Directed graphs with weighted edges, such as bags that contain a number
+//! of other bags.
+//!
+//! Nodes are interned: each distinct node is stored once and referred to by
+//! its `NodeId`, which is the order in which it was added.
+//!
+//! ```
+//! use aoc20::graph::{DiGraph, Direction};
+//!
+//! let mut graph = DiGraph::new();
+//! graph.add_edge("box", "bag", 2);
+//! graph.add_edge("bag", "purse", 3);
+//! let bag = graph.id("bag").unwrap();
+//! assert_eq!(graph.reachable(bag, Direction::Reverse).len(), 1);
+//!
+//! // Each box holds 2 bags, each of which holds 3 purses.
+//! let box_ = graph.id("box").unwrap();
+//! let total = graph.fold(box_, |_, children| {
+//!     children.iter().map(|(n, inside)| *n * (1 + inside)).sum::<usize>()
+//! });
+//! assert_eq!(total, Some(8));
+//! ```
+
+use std::borrow::Borrow;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// The index of a node in a graph.
+pub type NodeId = usize;
+
+/// Which way to follow the edges of a graph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// From the source of each edge to its target.
+    Forward,
+    /// From the target of each edge to its source.
+    Reverse,
+}
+
+/// A directed graph with nodes of type `N` and edge weights of type `W`.
+#[derive(Debug, Clone)]
+pub struct DiGraph<N, W> {
+    nodes: Vec<N>,
+    ids: HashMap<N, NodeId>,
+    edges: Vec<(NodeId, NodeId, W)>,
+    /// The indices in `edges` of the edges leaving and entering each node.
+    outgoing: Vec<Vec<usize>>,
+    incoming: Vec<Vec<usize>>,
+}
+
+impl<N, W> Default for DiGraph<N, W> {
+    fn default() -> Self {
+        DiGraph {
+            nodes: Vec::new(),
+            ids: HashMap::new(),
+            edges: Vec::new(),
+            outgoing: Vec::new(),
+            incoming: Vec::new(),
+        }
+    }
+}
+
+impl<N: Clone + Eq + Hash, W> DiGraph<N, W> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the id of the node, adding it to the graph if it is new.
+    pub fn intern(&mut self, node: N) -> NodeId {
+        if let Some(&id) = self.ids.get(&node) {
+            return id;
+        }
+        let id = self.nodes.len();
+        self.nodes.push(node.clone());
+        self.ids.insert(node, id);
+        self.outgoing.push(Vec::new());
+        self.incoming.push(Vec::new());
+        id
+    }
+
+    /// Adds an edge between the nodes, adding the nodes if they are new, and
+    /// returns their ids.
+    pub fn add_edge(&mut self, from: N, to: N, weight: W) -> (NodeId, NodeId) {
+        let from = self.intern(from);
+        let to = self.intern(to);
+        self.add_edge_between(from, to, weight);
+        (from, to)
+    }
+
+    /// Returns the id of the node, if it is in the graph.
+    pub fn id<Q>(&self, node: &Q) -> Option<NodeId>
+    where
+        N: Borrow<Q>,
+        Q: Eq + Hash + ?Sized,
+    {
+        self.ids.get(node).copied()
+    }
+}
+
+impl<N, W> DiGraph<N, W> {
+    /// Adds an edge between two nodes that are already in the graph.
+    ///
+    /// Panics if either node is not in the graph.
+    pub fn add_edge_between(&mut self, from: NodeId, to: NodeId, weight: W) {
+        for id in [from, to] {
+            assert!(id < self.nodes.len(), "node {} not in the graph", id);
+        }
+        self.outgoing[from].push(self.edges.len());
+        self.incoming[to].push(self.edges.len());
+        self.edges.push((from, to, weight));
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns the node with the id.
+    ///
+    /// Panics if there is no such node.
+    pub fn node(&self, id: NodeId) -> &N {
+        &self.nodes[id]
+    }
+
+    /// Returns the ids and nodes of the graph, in the order they were added.
+    pub fn nodes(&self) -> impl Iterator<Item = (NodeId, &N)> {
+        self.nodes.iter().enumerate()
+    }
+
+    /// Returns the other end and the weight of each edge of the node in the
+    /// direction, in the order the edges were added.
+    pub fn edges(&self, id: NodeId, direction: Direction) -> impl Iterator<Item = (NodeId, &W)> {
+        let (edges, forward) = match direction {
+            Direction::Forward => (&self.outgoing[id], true),
+            Direction::Reverse => (&self.incoming[id], false),
+        };
+        edges.iter().map(move |&i| {
+            let (from, to, ref weight) = self.edges[i];
+            (if forward { to } else { from }, weight)
+        })
+    }
+
+    fn neighbours(&self, id: NodeId, direction: Direction) -> impl Iterator<Item = NodeId> + '_ {
+        self.edges(id, direction).map(|(other, _)| other)
+    }
+
+    /// Returns the nodes reachable from `start`, including `start`, in
+    /// breadth-first order.
+    pub fn bfs(&self, start: NodeId, direction: Direction) -> Vec<NodeId> {
+        let mut seen = vec![false; self.len()];
+        let mut order = Vec::new();
+        let mut queue = VecDeque::from(vec![start]);
+        seen[start] = true;
+        while let Some(id) = queue.pop_front() {
+            order.push(id);
+            for next in self.neighbours(id, direction) {
+                if !seen[next] {
+                    seen[next] = true;
+                    queue.push_back(next);
+                }
+            }
+        }
+        order
+    }
+
+    /// Returns the nodes reachable from `start`, including `start`, in
+    /// depth-first preorder.
+    pub fn dfs(&self, start: NodeId, direction: Direction) -> Vec<NodeId> {
+        let mut seen = vec![false; self.len()];
+        let mut order = Vec::new();
+        let mut stack = vec![start];
+        while let Some(id) = stack.pop() {
+            if seen[id] {
+                continue;
+            }
+            seen[id] = true;
+            order.push(id);
+            let next: Vec<_> = self.neighbours(id, direction).collect();
+            stack.extend(next.into_iter().rev().filter(|&x| !seen[x]));
+        }
+        order
+    }
+
+    /// Returns the nodes that can be reached from `start` by following at
+    /// least one edge, which only includes `start` if it is on a cycle.
+    pub fn reachable(&self, start: NodeId, direction: Direction) -> HashSet<NodeId> {
+        let mut seen = HashSet::new();
+        let mut stack: Vec<_> = self.neighbours(start, direction).collect();
+        while let Some(id) = stack.pop() {
+            if seen.insert(id) {
+                stack.extend(self.neighbours(id, direction));
+            }
+        }
+        seen
+    }
+
+    /// Returns the nodes ordered so that every edge goes from an earlier to
+    /// a later node, or `None` if the graph has a cycle.
+    pub fn topological_order(&self) -> Option<Vec<NodeId>> {
+        let mut incoming: Vec<usize> = self.incoming.iter().map(Vec::len).collect();
+        let mut ready: Vec<_> = (0..self.len()).filter(|&x| incoming[x] == 0).collect();
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(id) = ready.pop() {
+            order.push(id);
+            for next in self.neighbours(id, Direction::Forward) {
+                incoming[next] -= 1;
+                if incoming[next] == 0 {
+                    ready.push(next);
+                }
+            }
+        }
+        if order.len() == self.len() {
+            Some(order)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the nodes of a cycle in the order of its edges, or `None` if
+    /// the graph has no cycle.
+    pub fn find_cycle(&self) -> Option<Vec<NodeId>> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum State {
+            New,
+            Active,
+            Done,
+        }
+        let mut state = vec![State::New; self.len()];
+        for root in 0..self.len() {
+            if state[root] != State::New {
+                continue;
+            }
+            // The path from the root, with the edges left to follow from
+            // each node on it.
+            let mut path = vec![(root, self.neighbours(root, Direction::Forward))];
+            state[root] = State::Active;
+            while let Some((_, next)) = path.last_mut() {
+                match next.next() {
+                    Some(id) if state[id] == State::Active => {
+                        let start = path.iter().position(|(x, _)| *x == id).unwrap();
+                        return Some(path[start..].iter().map(|(x, _)| *x).collect());
+                    }
+                    Some(id) if state[id] == State::New => {
+                        state[id] = State::Active;
+                        path.push((id, self.neighbours(id, Direction::Forward)));
+                    }
+                    Some(_) => {}
+                    None => {
+                        let (id, _) = path.pop().unwrap();
+                        state[id] = State::Done;
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    pub fn has_cycle(&self) -> bool {
+        self.find_cycle().is_some()
+    }
+
+    /// Computes a value for `start` from the values of the nodes its edges
+    /// lead to, such as the sum of the weights along all paths.
+    ///
+    /// `f` is given a node and, for each of its outgoing edges, the weight
+    /// and the value of the target node, and is called once per node that
+    /// can be reached from `start`. Returns `None` if a cycle can be reached
+    /// from `start`, since the value would then depend on itself.
+    pub fn fold<T, F>(&self, start: NodeId, mut f: F) -> Option<T>
+    where
+        T: Clone,
+        F: FnMut(NodeId, &[(&W, T)]) -> T,
+    {
+        let mut values: HashMap<NodeId, T> = HashMap::new();
+        let mut active = HashSet::new();
+        // Each node is visited twice: first to push its children, then to
+        // compute its value once theirs are known.
+        let mut stack = vec![(start, false)];
+        while let Some((id, children_done)) = stack.pop() {
+            if values.contains_key(&id) {
+                continue;
+            }
+            if children_done {
+                let children: Vec<_> = self
+                    .edges(id, Direction::Forward)
+                    .map(|(next, weight)| (weight, values[&next].clone()))
+                    .collect();
+                values.insert(id, f(id, &children));
+                active.remove(&id);
+            } else {
+                if !active.insert(id) {
+                    return None;
+                }
+                stack.push((id, true));
+                for next in self.neighbours(id, Direction::Forward) {
+                    if active.contains(&next) {
+                        return None;
+                    }
+                    if !values.contains_key(&next) {
+                        stack.push((next, false));
+                    }
+                }
+            }
+        }
+        values.remove(&start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// a -> b -> d, a -> c -> d, with weights 1 to 4.
+    fn diamond() -> DiGraph<&'static str, usize> {
+        let mut graph = DiGraph::new();
+        graph.add_edge("a", "b", 1);
+        graph.add_edge("a", "c", 2);
+        graph.add_edge("b", "d", 3);
+        graph.add_edge("c", "d", 4);
+        graph
+    }
+
+    #[test]
+    fn test_interning() {
+        let mut graph = diamond();
+        assert_eq!(graph.len(), 4);
+        assert_eq!(graph.intern("c"), 2);
+        assert_eq!(graph.intern("e"), 4);
+        assert_eq!(graph.id("d"), Some(3));
+        assert_eq!(graph.id("f"), None);
+        assert_eq!(*graph.node(1), "b");
+
+        let mut graph: DiGraph<String, ()> = DiGraph::new();
+        graph.intern("x".to_string());
+        assert_eq!(graph.id("x"), Some(0));
+    }
+
+    #[test]
+    fn test_edges() {
+        let graph = diamond();
+        let edges: Vec<_> = graph.edges(0, Direction::Forward).collect();
+        assert_eq!(edges, [(1, &1), (2, &2)]);
+        let edges: Vec<_> = graph.edges(3, Direction::Reverse).collect();
+        assert_eq!(edges, [(1, &3), (2, &4)]);
+    }
+
+    #[test]
+    fn test_traversal() {
+        let graph = diamond();
+        assert_eq!(graph.bfs(0, Direction::Forward), [0, 1, 2, 3]);
+        assert_eq!(graph.dfs(0, Direction::Forward), [0, 1, 3, 2]);
+        assert_eq!(graph.bfs(3, Direction::Reverse), [3, 1, 2, 0]);
+        assert_eq!(graph.reachable(3, Direction::Reverse), [0, 1, 2].into());
+        assert!(graph.reachable(3, Direction::Forward).is_empty());
+    }
+
+    #[test]
+    fn test_cycles() {
+        let mut graph = diamond();
+        assert_eq!(graph.topological_order().unwrap()[0], 0);
+        assert_eq!(graph.find_cycle(), None);
+
+        graph.add_edge("d", "b", 5);
+        assert_eq!(graph.topological_order(), None);
+        assert_eq!(graph.find_cycle(), Some(vec![1, 3]));
+        assert!(graph.reachable(1, Direction::Forward).contains(&1));
+    }
+
+    #[test]
+    fn test_fold() {
+        let mut graph = diamond();
+        // The number of paths from each node to `d`, and the total weight.
+        let paths = graph.fold(0, |_, children| {
+            children.iter().map(|(_, x)| *x).sum::<usize>().max(1)
+        });
+        assert_eq!(paths, Some(2));
+        let mut calls = 0;
+        let weight = graph.fold(0, |_, children| {
+            calls += 1;
+            children.iter().map(|(w, x)| **w + x).sum::<usize>()
+        });
+        assert_eq!((weight, calls), (Some(10), 4));
+
+        graph.add_edge("d", "b", 5);
+        assert_eq!(graph.fold(0, |_, _| 0), None);
+        assert_eq!(graph.fold(2, |_, _| 0), None);
+    }
+}
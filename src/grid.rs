@@ -0,0 +1,342 @@
+//! Two-dimensional grids of cells, such as the maps in many puzzles.
+//!
+//! Positions are `(row, column)` pairs, with `(0, 0)` in the top-left corner.
+//!
+//! ```
+//! use aoc20::grid::Grid;
+//! use aoc20::ParseError;
+//!
+//! let grid = Grid::parse_with("#.\n.#", |c| Ok::<_, ParseError>(c == '#')).unwrap();
+//! assert_eq!((grid.rows(), grid.columns()), (2, 2));
+//! assert_eq!(grid.get((1, 1)), Some(&true));
+//! assert_eq!(grid.get((2, 0)), None);
+//! assert_eq!(grid.get_torus((-1, 2)), &false);
+//! assert_eq!(grid.neighbours8((0, 0)).filter(|&p| grid[p]).count(), 1);
+//! ```
+
+use crate::ParseError;
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use thiserror::Error;
+
+/// A `(row, column)` position in a grid.
+pub type Pos = (usize, usize);
+
+/// The offsets of the 4-connected neighbours: up, left, right, and down.
+const NEIGHBOURS4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// The offsets of the 8-connected neighbours, row by row.
+const NEIGHBOURS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    rows: usize,
+    columns: usize,
+}
+
+#[derive(Error, Debug)]
+pub enum GridError {
+    #[error("empty grid")]
+    Empty,
+    #[error("row of grid has {found} columns instead of {expected}")]
+    InvalidRow { expected: usize, found: usize },
+}
+
+impl From<GridError> for crate::Error {
+    fn from(err: GridError) -> Self {
+        crate::Error::parse(err)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Returns a grid with every cell set to `value`.
+    pub fn new(rows: usize, columns: usize, value: T) -> Self {
+        Grid {
+            cells: vec![value; rows * columns],
+            rows,
+            columns,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Returns a grid of the given cells, row by row.
+    ///
+    /// Panics if there are not `rows * columns` cells.
+    pub fn from_cells(rows: usize, columns: usize, cells: Vec<T>) -> Self {
+        assert_eq!(cells.len(), rows * columns, "wrong number of cells");
+        Grid {
+            cells,
+            rows,
+            columns,
+        }
+    }
+
+    /// Parses a map with one character per cell, turning each character into
+    /// a cell with `cell`, and ignoring indentation and blank lines around
+    /// the map.
+    ///
+    /// An error from `cell` is moved to the character in the line it was
+    /// returned for, and every row must be as long as the first.
+    pub fn parse_with<F>(s: &str, mut cell: F) -> Result<Self, ParseError>
+    where
+        F: FnMut(char) -> Result<T, ParseError>,
+    {
+        // Rows are trimmed, but errors point into the untrimmed line, so
+        // that their columns match the input.
+        let lines: Vec<_> = s.lines().map(|l| (l, l.trim())).enumerate().collect();
+        let start = lines.iter().position(|(_, (_, l))| !l.is_empty());
+        let end = lines.iter().rposition(|(_, (_, l))| !l.is_empty());
+        let lines = match (start, end) {
+            (Some(start), Some(end)) => &lines[start..=end],
+            _ => return Err(ParseError::new(s.trim(), GridError::Empty)),
+        };
+
+        let columns = (lines[0].1).1.chars().count();
+        let mut cells = Vec::with_capacity(lines.len() * columns);
+        for &(i, (text, line)) in lines {
+            let found = line.chars().count();
+            if found != columns {
+                return Err(ParseError::at(
+                    text,
+                    line,
+                    GridError::InvalidRow {
+                        expected: columns,
+                        found,
+                    },
+                )
+                .with_hint("all rows must be as long as the first")
+                .at_line(i + 1));
+            }
+            for (j, c) in line.char_indices() {
+                let value = cell(c)
+                    .map_err(|err| err.within(text, &line[j..j + c.len_utf8()]).at_line(i + 1))?;
+                cells.push(value);
+            }
+        }
+        Ok(Self::from_cells(lines.len(), columns, cells))
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Returns true if the position is inside the grid.
+    pub fn contains(&self, (row, col): Pos) -> bool {
+        row < self.rows && col < self.columns
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        if self.contains(pos) {
+            Some(&self.cells[pos.0 * self.columns + pos.1])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.0 * self.columns + pos.1])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the cell at the position on a grid that repeats to the left
+    /// and right, or `None` if the row is outside the grid.
+    pub fn get_cylinder(&self, (row, col): (isize, isize)) -> Option<&T> {
+        if row < 0 || self.columns == 0 {
+            return None;
+        }
+        self.get((row as usize, col.rem_euclid(self.columns as isize) as usize))
+    }
+
+    /// Returns the cell at the position on a grid that repeats in all
+    /// directions.
+    ///
+    /// Panics if the grid is empty.
+    pub fn get_torus(&self, (row, col): (isize, isize)) -> &T {
+        let row = row.rem_euclid(self.rows as isize) as usize;
+        let col = col.rem_euclid(self.columns as isize) as usize;
+        &self[(row, col)]
+    }
+
+    /// Returns the positions next to `pos` in the four main directions that
+    /// are inside the grid.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.offsets(pos, &NEIGHBOURS4)
+    }
+
+    /// Returns the positions next to `pos`, including diagonally, that are
+    /// inside the grid.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        self.offsets(pos, &NEIGHBOURS8)
+    }
+
+    fn offsets<'a>(
+        &'a self,
+        (row, col): Pos,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = Pos> + 'a {
+        offsets.iter().filter_map(move |&(dr, dc)| {
+            let pos = (row.checked_add_signed(dr)?, col.checked_add_signed(dc)?);
+            Some(pos).filter(|&pos| self.contains(pos))
+        })
+    }
+
+    /// Returns the cells of the row.
+    ///
+    /// Panics if the row is outside the grid.
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.rows, "row {} outside the grid", row);
+        &self.cells[row * self.columns..(row + 1) * self.columns]
+    }
+
+    /// Returns the cells of the column, from top to bottom.
+    ///
+    /// Panics if the column is outside the grid.
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        assert!(col < self.columns, "column {} outside the grid", col);
+        self.cells.iter().skip(col).step_by(self.columns)
+    }
+
+    /// Returns the rows of the grid, from top to bottom.
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.rows).map(move |row| self.row(row))
+    }
+
+    /// Returns the columns of the grid, from left to right.
+    pub fn iter_columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.columns).map(move |col| self.column(col))
+    }
+
+    /// Returns every position and its cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        let columns = self.columns;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, x)| ((i / columns, i % columns), x))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("position {:?} outside the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("position {:?} outside the grid", pos))
+    }
+}
+
+/// Writes one line per row, with each cell written by its own `Display`.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for row in self.iter_rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(s: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse_with(s, |c| {
+            c.to_digit(10)
+                .ok_or_else(|| ParseError::new(&c.to_string(), "not a digit"))
+        })
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = digits("\n  123\n  456\n\n").unwrap();
+        assert_eq!(grid, Grid::from_cells(2, 3, vec![1, 2, 3, 4, 5, 6]));
+        assert_eq!(grid.to_string(), "123\n456\n");
+
+        let err = digits("123\n4é6\n4x6").unwrap_err();
+        assert_eq!((err.line(), err.columns()), (2, 1..2));
+        assert_eq!(err.text(), "4é6");
+
+        let err = digits("123\n45\n").unwrap_err();
+        assert_eq!((err.line(), err.columns()), (2, 0..2));
+        assert!(matches!(
+            err.kind().downcast_ref(),
+            Some(GridError::InvalidRow {
+                expected: 3,
+                found: 2
+            })
+        ));
+        let err = digits("  123\n  4x6").unwrap_err();
+        assert_eq!((err.line(), err.columns(), err.text()), (2, 3..4, "  4x6"));
+        assert!(digits(" \n").is_err());
+    }
+
+    #[test]
+    fn test_indexing() {
+        let mut grid = digits("123\n456").unwrap();
+        assert_eq!(grid[(1, 0)], 4);
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid.get_cylinder((1, 4)), Some(&5));
+        assert_eq!(grid.get_cylinder((1, -1)), Some(&6));
+        assert_eq!(grid.get_cylinder((2, 0)), None);
+        assert_eq!(grid.get_torus((-1, -4)), &6);
+        grid[(0, 0)] = 9;
+        *grid.get_mut((1, 2)).unwrap() = 0;
+        assert_eq!(grid.to_string(), "923\n450\n");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 3, ());
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            [(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbours4((1, 1)).count(), 4);
+        assert_eq!(
+            grid.neighbours8((2, 1)).collect::<Vec<_>>(),
+            [(1, 0), (1, 1), (1, 2), (2, 0), (2, 2)]
+        );
+        assert_eq!(grid.neighbours8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = digits("123\n456").unwrap();
+        assert_eq!(grid.row(1), [4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), [3, 6]);
+        assert_eq!(grid.iter_rows().count(), 2);
+        let sums: Vec<u32> = grid.iter_columns().map(|c| c.sum()).collect();
+        assert_eq!(sums, [5, 7, 9]);
+        assert_eq!(grid.iter().nth(4), Some(((1, 1), &5)));
+    }
+}
@@ -0,0 +1,562 @@
+//! Reading the program input from the built-in default, a file, or stdin.
+
+use crate::Day;
+
+use clap::{Arg, ArgMatches};
+use std::env;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+/// The name of the input that is built into each day.
+pub const DEFAULT_INPUT: &str = "default";
+
+/// The environment variable that overrides the input directory.
+pub const INPUT_DIR_VAR: &str = "AOC20_INPUT_DIR";
+
+#[derive(Debug)]
+enum Source {
+    Default,
+    File(PathBuf),
+    Stdin,
+    /// A glob pattern that matched no file, or whose directory cannot be
+    /// read, which fails with the error when it is read.
+    Unreadable(InputError),
+}
+
+/// The command line options that only some days have; see `Day::options`.
+pub(crate) const DAY_OPTIONS: &[&str] = &["target", "k"];
+
+/// The input of a program, which is read when it is first needed.
+///
+/// The input can be read as a whole with `to_str`, or one line at a time with
+/// `lines`, which does not hold more than a line in memory.
+#[derive(Debug)]
+pub struct ProgramInput {
+    name: String,
+    source: Source,
+    data: Option<String>,
+    print: bool,
+    read_time: Duration,
+}
+
+impl ProgramInput {
+    /// Returns the command line arguments understood by `all_from_matches`.
+    ///
+    /// The INPUT argument is positional, so it is placed after any
+    /// positional arguments the caller has already defined.
+    pub fn args<'a, 'b>() -> Vec<Arg<'a, 'b>> {
+        vec![
+            Arg::with_name("INPUT")
+                .multiple(true)
+                .help("Input files or glob patterns such as inputs/day07-*.txt, use - for stdin"),
+            Arg::with_name("input-name")
+                .long("input-name")
+                .value_name("NAME")
+                .conflicts_with("INPUT")
+                .help("Use the named input variant dayNN-NAME.txt from the input directory"),
+            Arg::with_name("verbose")
+                .short("v")
+                .long("verbose")
+                .multiple(true)
+                .help("Print the default input used"),
+            Arg::with_name("stream")
+                .long("stream")
+                .conflicts_with_all(&["time", "repeat"])
+                .help("Process the input one line at a time, for days that support it"),
+            Arg::with_name("part")
+                .long("part")
+                .value_name("PART")
+                .possible_values(&["1", "2"])
+                .help("Solve only the given part of the puzzle"),
+            Arg::with_name("watch")
+                .long("watch")
+                .help("Run again whenever an input file changes, until interrupted"),
+            Arg::with_name("check")
+                .long("check")
+                .help("Compare the answers with those in the answers file"),
+            Arg::with_name("answers")
+                .long("answers")
+                .value_name("FILE")
+                .default_value("answers.txt")
+                .help("Answers file used by --check"),
+            Arg::with_name("time")
+                .long("time")
+                .help("Print how long reading, parsing, and each part take"),
+            Arg::with_name("repeat")
+                .long("repeat")
+                .value_name("N")
+                .validator(|x| match x.parse::<usize>() {
+                    Ok(n) if n > 0 => Ok(()),
+                    _ => Err("must be a positive number".into()),
+                })
+                .help("Run the solution N times and print min/median/max, implies --time"),
+            Arg::with_name("describe")
+                .long("describe")
+                .conflicts_with_all(&["INPUT", "input-name", "watch", "generate"])
+                .help("Print the puzzle description wrapped to the terminal width, or only the part given with --part"),
+            Arg::with_name("generate")
+                .long("generate")
+                .value_name("N")
+                .conflicts_with_all(&["INPUT", "input-name", "watch"])
+                .validator(|x| match x.parse::<usize>() {
+                    Ok(_) => Ok(()),
+                    _ => Err("must be a number".into()),
+                })
+                .help("Print a random input with N records instead of solving"),
+            Arg::with_name("seed")
+                .long("seed")
+                .value_name("S")
+                .requires("generate")
+                .validator(|x| match x.parse::<u64>() {
+                    Ok(_) => Ok(()),
+                    _ => Err("must be a number".into()),
+                })
+                .help("Seed for --generate, which is random by default"),
+            Arg::with_name("invalid")
+                .long("invalid")
+                .value_name("FRACTION")
+                .requires("generate")
+                .validator(|x| match x.parse::<f64>() {
+                    Ok(p) if (0.0..=1.0).contains(&p) => Ok(()),
+                    _ => Err("must be a number from 0 to 1".into()),
+                })
+                .help("Fraction of invalid passports for --generate, 0.2 by default"),
+            Arg::with_name("target")
+                .long("target")
+                .value_name("SUM")
+                .allow_hyphen_values(true)
+                .validator(|x| match x.parse::<i64>() {
+                    Ok(_) => Ok(()),
+                    _ => Err("must be a number".into()),
+                })
+                .help("Sum that the entries must add up to for day 1, 2020 by default"),
+            Arg::with_name("k")
+                .long("k")
+                .value_name("K")
+                .validator(|x| match x.parse::<usize>() {
+                    Ok(k) if k > 0 => Ok(()),
+                    _ => Err("must be a positive number".into()),
+                })
+                .help(
+                    "Number of entries that must add up to the target in part two of day 1, \
+                     3 by default; above 3, N entries can take up to N^(K/2) time and memory",
+                ),
+            Arg::with_name("format")
+                .long("format")
+                .value_name("FORMAT")
+                .possible_values(&["text", "json"])
+                .default_value("text")
+                .help("Output format, json prints one object per run"),
+        ]
+    }
+
+    /// Returns all inputs given on the command line for the day, which is
+    /// never empty.
+    ///
+    /// Each INPUT argument is a file, `-` for stdin, or a glob pattern. A
+    /// pattern that matches no file is kept as an input that fails when it
+    /// is read, like a missing file, so that it does not stop the others. Without an INPUT argument, the input is
+    /// looked up in the input directory (see `input_path`), and if there is
+    /// none, the built-in input of the day is used.
+    pub fn all_from_matches(matches: &ArgMatches, day: &Day) -> Result<Vec<Self>, InputError> {
+        let verbose = matches.occurrences_of("verbose");
+        let input = if let Some(values) = matches.values_of("INPUT") {
+            let mut inputs = Vec::new();
+            for input in values {
+                if input == "-" {
+                    inputs.push(Self::with_source(input, Source::Stdin, verbose > 2));
+                } else if is_glob(input) {
+                    match glob(input) {
+                        Ok(paths) => {
+                            for path in paths {
+                                let name = path.display().to_string();
+                                let source = Source::File(path);
+                                inputs.push(Self::with_source(&name, source, verbose > 1));
+                            }
+                        }
+                        Err(err) => {
+                            inputs.push(Self::with_source(input, Source::Unreadable(err), false))
+                        }
+                    }
+                } else {
+                    inputs.push(Self::with_source(
+                        input,
+                        Source::File(input.into()),
+                        verbose > 1,
+                    ));
+                }
+            }
+            return Ok(inputs);
+        } else if let Some(name) = matches.value_of("input-name") {
+            let path = input_path(day.day, Some(name));
+            Self::with_source(name, Source::File(path), verbose > 1)
+        } else {
+            let path = input_path(day.day, None);
+            if path.is_file() {
+                Self::with_source(&path.display().to_string(), Source::File(path), verbose > 1)
+            } else {
+                let mut input = Self::with_source(DEFAULT_INPUT, Source::Default, verbose > 0);
+                input.data = Some(String::from(day.input.trim()));
+                input
+            }
+        };
+        Ok(vec![input])
+    }
+
+    fn with_source(name: &str, source: Source, print: bool) -> Self {
+        ProgramInput {
+            name: name.into(),
+            source,
+            data: None,
+            print,
+            read_time: Duration::default(),
+        }
+    }
+
+    /// Returns the name of the input, which is the path of the file read,
+    /// `-` for stdin, the variant given with `--input-name`, or `default` for
+    /// the built-in input.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the path of the input file, unless the input is read from
+    /// stdin or built in.
+    pub fn path(&self) -> Option<&Path> {
+        match &self.source {
+            Source::File(path) => Some(path),
+            _ => None,
+        }
+    }
+
+    /// Returns the time spent reading the input so far.
+    pub fn read_time(&self) -> Duration {
+        self.read_time
+    }
+
+    pub fn to_str(&mut self) -> Result<&str, InputError> {
+        if self.data.is_none() {
+            let start = Instant::now();
+            self.data = Some(match &self.source {
+                Source::File(path) => read_file(path)?,
+                Source::Unreadable(err) => return Err(err.duplicate()),
+                _ => {
+                    eprintln!(":: Reading from stdin...");
+                    read_stdin()?
+                }
+            });
+            self.read_time += start.elapsed();
+        }
+        if self.print {
+            eprintln!(":: Program input is:\n{}\n", self.data.as_ref().unwrap());
+            self.print = false;
+        }
+        Ok(self.data.as_ref().unwrap().as_str())
+    }
+
+    /// Returns an iterator over the lines of the input, without line endings.
+    ///
+    /// Files and stdin are read one line at a time, unless the input has
+    /// already been read with `to_str`. If the input cannot be opened, the
+    /// iterator yields only that error.
+    pub fn lines(&mut self) -> impl Iterator<Item = Result<String, InputError>> + '_ {
+        let lines: Box<dyn Iterator<Item = Result<String, InputError>>> =
+            match (&self.data, &self.source) {
+                (Some(data), _) => Box::new(data.lines().map(|x| Ok(x.to_string()))),
+                (None, Source::File(path)) => {
+                    let name = path.display().to_string();
+                    match File::open(path) {
+                        Ok(f) => Box::new(read_lines(BufReader::new(f), name)),
+                        Err(err) => Box::new(Some(Err(open_error(err, &name))).into_iter()),
+                    }
+                }
+                (None, Source::Unreadable(err)) => Box::new(Some(Err(err.duplicate())).into_iter()),
+                (None, _) => Box::new(read_lines(io::stdin().lock(), "-".into())),
+            };
+        lines
+    }
+}
+
+fn read_lines<R: BufRead>(
+    reader: R,
+    path: String,
+) -> impl Iterator<Item = Result<String, InputError>> {
+    reader.lines().map(move |line| {
+        line.map_err(|err| match err.kind() {
+            io::ErrorKind::InvalidData => InputError::InvalidUtf8 { path: path.clone() },
+            _ if path == "-" => InputError::Stdin {
+                path: path.clone(),
+                source: err,
+            },
+            _ => InputError::Io {
+                path: path.clone(),
+                source: err,
+            },
+        })
+    })
+}
+
+/// Returns the path of the input file for the day in the input directory.
+///
+/// The input directory is given by the `AOC20_INPUT_DIR` environment variable
+/// and defaults to `inputs`. The input for day 7 is `day07.txt`, and its
+/// variant `alice` is `day07-alice.txt`.
+pub fn input_path(day: usize, name: Option<&str>) -> PathBuf {
+    let dir = env::var_os(INPUT_DIR_VAR).map_or_else(|| PathBuf::from("inputs"), PathBuf::from);
+    match name {
+        Some(name) => dir.join(format!("day{:02}-{}.txt", day, name)),
+        None => dir.join(format!("day{:02}.txt", day)),
+    }
+}
+
+fn is_glob(pattern: &str) -> bool {
+    pattern.contains(['*', '?'])
+}
+
+/// Returns the files matching the pattern in sorted order, where `*` matches
+/// any number of characters and `?` matches one character.
+///
+/// Only the file name may contain wildcards, not the directories before it.
+fn glob(pattern: &str) -> Result<Vec<PathBuf>, InputError> {
+    let path = Path::new(pattern);
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    let name = path.file_name().and_then(|x| x.to_str()).unwrap_or("");
+    let dir_name = dir.display().to_string();
+
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(dir).map_err(|err| open_error(err, &dir_name))? {
+        let entry = entry.map_err(|err| open_error(err, &dir_name))?;
+        let matched = entry
+            .file_name()
+            .to_str()
+            .is_some_and(|x| wildcard_match(name, x));
+        if matched && entry.path().is_file() {
+            paths.push(match path.parent() {
+                Some(parent) => parent.join(entry.file_name()),
+                None => entry.file_name().into(),
+            });
+        }
+    }
+    if paths.is_empty() {
+        return Err(InputError::NoMatch {
+            path: pattern.into(),
+        });
+    }
+    paths.sort();
+    Ok(paths)
+}
+
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+
+    // matches[j] is true if the pattern so far matches the first j characters
+    let mut matches = vec![false; name.len() + 1];
+    matches[0] = true;
+    for p in pattern {
+        let mut next = vec![false; name.len() + 1];
+        for j in 0..=name.len() {
+            next[j] = match p {
+                '*' => matches[j] || (j > 0 && next[j - 1]),
+                '?' => j > 0 && matches[j - 1],
+                c => j > 0 && matches[j - 1] && name[j - 1] == c,
+            };
+        }
+        matches = next;
+    }
+    matches[name.len()]
+}
+
+pub(crate) fn read_file<P: AsRef<Path>>(path: P) -> Result<String, InputError> {
+    let path = path.as_ref();
+    let name = &path.display().to_string();
+    let bytes = fs::read(path).map_err(|err| open_error(err, name))?;
+    String::from_utf8(bytes).map_err(|_| InputError::InvalidUtf8 { path: name.into() })
+}
+
+fn open_error(err: io::Error, path: &str) -> InputError {
+    match err.kind() {
+        io::ErrorKind::NotFound => InputError::NotFound { path: path.into() },
+        io::ErrorKind::PermissionDenied => InputError::PermissionDenied { path: path.into() },
+        _ => InputError::Io {
+            path: path.into(),
+            source: err,
+        },
+    }
+}
+
+fn read_stdin() -> Result<String, InputError> {
+    let mut bytes = Vec::new();
+    io::stdin()
+        .read_to_end(&mut bytes)
+        .map_err(|err| InputError::Stdin {
+            path: "-".into(),
+            source: err,
+        })?;
+    String::from_utf8(bytes).map_err(|_| InputError::InvalidUtf8 { path: "-".into() })
+}
+
+/// An error that occurred while reading the program input.
+///
+/// The path is the one given on the command line, so `-` for stdin.
+#[derive(Error, Debug)]
+pub enum InputError {
+    #[error("input file not found: {path}")]
+    NotFound { path: String },
+
+    #[error("no input file matches: {path}")]
+    NoMatch { path: String },
+
+    #[error("permission denied: {path}")]
+    PermissionDenied { path: String },
+
+    #[error("input is not valid UTF-8: {path}")]
+    InvalidUtf8 { path: String },
+
+    #[error("cannot read from stdin: {source}")]
+    Stdin { path: String, source: io::Error },
+
+    #[error("cannot read input file {path}: {source}")]
+    Io { path: String, source: io::Error },
+}
+
+impl InputError {
+    /// Returns a copy of the error, where the source of an I/O error keeps
+    /// only its kind and message.
+    fn duplicate(&self) -> Self {
+        let path = self.path().to_string();
+        match self {
+            InputError::NotFound { .. } => InputError::NotFound { path },
+            InputError::NoMatch { .. } => InputError::NoMatch { path },
+            InputError::PermissionDenied { .. } => InputError::PermissionDenied { path },
+            InputError::InvalidUtf8 { .. } => InputError::InvalidUtf8 { path },
+            InputError::Stdin { source, .. } => InputError::Stdin {
+                path,
+                source: io::Error::new(source.kind(), source.to_string()),
+            },
+            InputError::Io { source, .. } => InputError::Io {
+                path,
+                source: io::Error::new(source.kind(), source.to_string()),
+            },
+        }
+    }
+
+    pub fn path(&self) -> &str {
+        match self {
+            InputError::NotFound { path }
+            | InputError::NoMatch { path }
+            | InputError::PermissionDenied { path }
+            | InputError::InvalidUtf8 { path }
+            | InputError::Stdin { path, .. }
+            | InputError::Io { path, .. } => path,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process;
+
+    #[test]
+    fn test_read_file_errors() {
+        let dir = env::temp_dir().join(format!("aoc20-test-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("invalid.txt");
+        fs::write(&path, b"\xff\xfe").unwrap();
+        let path = path.to_str().unwrap();
+
+        assert!(matches!(
+            read_file(path),
+            Err(InputError::InvalidUtf8 { path: p }) if p == path
+        ));
+        assert!(matches!(
+            read_file("does/not/exist"),
+            Err(InputError::NotFound { .. })
+        ));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_read_file_non_utf8_path() {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+
+        let dir = env::temp_dir().join(format!("aoc20-test-non-utf8-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(OsStr::from_bytes(b"input-\xff.txt"));
+        fs::write(&path, "1\n").unwrap();
+
+        assert_eq!(read_file(&path).unwrap(), "1\n");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_lines() {
+        let dir = env::temp_dir().join(format!("aoc20-test-lines-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("input.txt");
+        fs::write(&path, "a\r\nb\n\nc\n").unwrap();
+
+        let mut input = ProgramInput::with_source("input", Source::File(path), false);
+        let lines: Vec<_> = input.lines().map(|x| x.unwrap()).collect();
+        assert_eq!(lines, vec!["a", "b", "", "c"]);
+
+        let mut input = ProgramInput::with_source("nope", Source::File(dir.join("nope")), false);
+        let lines: Vec<_> = input.lines().collect();
+        assert!(matches!(lines[..], [Err(InputError::NotFound { .. })]));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_glob() {
+        let dir = env::temp_dir().join(format!("aoc20-test-glob-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for name in &[
+            "day07-bob.txt",
+            "day07-alice.txt",
+            "day07.txt",
+            "day06-bob.txt",
+        ] {
+            fs::write(dir.join(name), "").unwrap();
+        }
+
+        let pattern = dir.join("day07-*.txt");
+        let paths = glob(pattern.to_str().unwrap()).unwrap();
+        assert_eq!(
+            paths,
+            vec![dir.join("day07-alice.txt"), dir.join("day07-bob.txt")]
+        );
+        let pattern = dir.join("day0?-bob.txt");
+        assert_eq!(glob(pattern.to_str().unwrap()).unwrap().len(), 2);
+        let pattern = dir.join("day08*");
+        assert!(matches!(
+            glob(pattern.to_str().unwrap()),
+            Err(InputError::NoMatch { .. })
+        ));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("*", ""));
+        assert!(wildcard_match("day*.txt", "day07-alice.txt"));
+        assert!(wildcard_match("a*b*c", "aXbYbc"));
+        assert!(!wildcard_match("a*b*c", "aXbYbcd"));
+        assert!(wildcard_match("d?y", "day"));
+        assert!(!wildcard_match("d?y", "dy"));
+    }
+
+    #[test]
+    fn test_input_path() {
+        assert!(input_path(7, None).ends_with("day07.txt"));
+        assert!(input_path(7, Some("alice")).ends_with("day07-alice.txt"));
+    }
+}
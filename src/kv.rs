@@ -0,0 +1,280 @@
+//! Records of `key:value` fields, with typed and validated access to their
+//! values.
+//!
+//! Errors point at the value of the field in its line, or at the first line
+//! of the record for missing fields, and name the field they are about.
+//!
+//! ```
+//! use aoc20::kv::Fields;
+//!
+//! let fields = Fields::parse("name:box size:12\ncolor:red").unwrap();
+//! assert_eq!(fields.get_str("name").unwrap(), "box");
+//! assert_eq!(fields.get_in_range("size", 1..=20).unwrap(), 12);
+//! assert_eq!(fields.get_opt::<u32>("weight").unwrap(), None);
+//! assert!(fields.get_in_range("size", 1..=10).is_err());
+//! ```
+
+use crate::ParseError;
+
+use regex::Regex;
+use std::collections::HashMap;
+use std::error;
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// The `key:value` fields of a record, which are separated by whitespace
+/// within and across lines. The key ends at the first `:`, so values may
+/// contain more of them.
+///
+/// A key that occurs more than once has the last of its values.
+#[derive(Debug)]
+pub struct Fields<'a> {
+    fields: HashMap<&'a str, Field<'a>>,
+    first_line: &'a str,
+}
+
+/// The value of a field and where it is in the record.
+#[derive(Debug)]
+struct Field<'a> {
+    value: &'a str,
+    line: usize,
+    text: &'a str,
+}
+
+#[derive(Error, Debug)]
+pub enum KvError {
+    #[error("malformed field: {data:?}")]
+    Malformed { data: String },
+
+    #[error("missing field: {field}")]
+    MissingField { field: String },
+
+    #[error("invalid value for field {field}: {value:?}")]
+    InvalidValue { field: String, value: String },
+
+    #[error("value out of range for field {field}: {value:?}")]
+    OutOfRange { field: String, value: String },
+}
+
+impl From<KvError> for crate::Error {
+    fn from(err: KvError) -> Self {
+        crate::Error::parse(err)
+    }
+}
+
+impl<'a> Fields<'a> {
+    /// Parses the fields of the record.
+    pub fn parse(s: &'a str) -> Result<Self, ParseError> {
+        let mut fields = HashMap::new();
+        for (i, line) in s.lines().enumerate() {
+            for pair in line.split_whitespace() {
+                match pair.split_once(':') {
+                    Some((key, value)) => {
+                        let field = Field {
+                            value,
+                            line: i,
+                            text: line,
+                        };
+                        fields.insert(key, field);
+                    }
+                    _ => {
+                        let err = KvError::Malformed { data: pair.into() };
+                        return Err(ParseError::at(line, pair, err)
+                            .with_hint("expected a `key:value` pair")
+                            .at_line(i + 1));
+                    }
+                }
+            }
+        }
+        Ok(Fields {
+            fields,
+            first_line: s.lines().next().unwrap_or(""),
+        })
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.fields.contains_key(key)
+    }
+
+    /// Returns the keys of the fields, in no particular order.
+    pub fn keys(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.fields.keys().copied()
+    }
+
+    /// Returns the value of the field, or an error if it is missing.
+    pub fn get_str(&self, key: &str) -> Result<&'a str, ParseError> {
+        self.field(key).map(|field| field.value)
+    }
+
+    /// Returns the value of the field parsed as `T`, or an error if it is
+    /// missing or cannot be parsed.
+    pub fn get<T>(&self, key: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let field = self.field(key)?;
+        field.value.parse().map_err(|err: T::Err| {
+            let kind = KvError::InvalidValue {
+                field: key.into(),
+                value: field.value.into(),
+            };
+            self.error(field, kind, err.to_string())
+        })
+    }
+
+    /// Returns the value of the field parsed as `T`, if it is present.
+    pub fn get_opt<T>(&self, key: &str) -> Result<Option<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        if self.contains(key) {
+            self.get(key).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Returns the value of the field parsed as a number in the range.
+    pub fn get_in_range<T>(&self, key: &str, range: RangeInclusive<T>) -> Result<T, ParseError>
+    where
+        T: FromStr + PartialOrd + Display,
+    {
+        let field = self.field(key)?;
+        let hint = format!(
+            "expected a number from {} to {}",
+            range.start(),
+            range.end()
+        );
+        match field.value.parse() {
+            Ok(x) if range.contains(&x) => Ok(x),
+            Ok(_) => {
+                let kind = KvError::OutOfRange {
+                    field: key.into(),
+                    value: field.value.into(),
+                };
+                Err(self.error(field, kind, hint))
+            }
+            Err(_) => {
+                let kind = KvError::InvalidValue {
+                    field: key.into(),
+                    value: field.value.into(),
+                };
+                Err(self.error(field, kind, hint))
+            }
+        }
+    }
+
+    /// Returns the value of the field if it matches the regex, which should
+    /// be anchored with `^` and `$` to match the whole value.
+    pub fn get_matching(&self, key: &str, re: &Regex, hint: &str) -> Result<&'a str, ParseError> {
+        let field = self.field(key)?;
+        if re.is_match(field.value) {
+            Ok(field.value)
+        } else {
+            let kind = KvError::InvalidValue {
+                field: key.into(),
+                value: field.value.into(),
+            };
+            Err(self.error(field, kind, hint))
+        }
+    }
+
+    /// Returns an error of the given kind that points at the value of the
+    /// field, for checks that the getters do not cover, or a missing field
+    /// error if there is no such field.
+    pub fn invalid<E>(&self, key: &str, kind: E, hint: &str) -> ParseError
+    where
+        E: Into<Box<dyn error::Error + Send + Sync>>,
+    {
+        match self.field(key) {
+            Ok(field) => self.error(field, kind, hint),
+            Err(err) => err,
+        }
+    }
+
+    fn field(&self, key: &str) -> Result<&Field<'a>, ParseError> {
+        self.fields.get(key).ok_or_else(|| {
+            let kind = KvError::MissingField { field: key.into() };
+            ParseError::new(self.first_line, kind).with_hint(format!("expected a `{}` field", key))
+        })
+    }
+
+    fn error<E, S>(&self, field: &Field, kind: E, hint: S) -> ParseError
+    where
+        E: Into<Box<dyn error::Error + Send + Sync>>,
+        S: Into<String>,
+    {
+        ParseError::at(field.text, field.value, kind)
+            .with_hint(hint)
+            .at_line(field.line + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RECORD: &str = "a:1 b:x\nc:#123 d:40";
+
+    fn kind(err: &ParseError) -> &KvError {
+        err.kind().downcast_ref().unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let fields = Fields::parse(RECORD).unwrap();
+        let mut keys: Vec<_> = fields.keys().collect();
+        keys.sort_unstable();
+        assert_eq!(keys, ["a", "b", "c", "d"]);
+
+        let err = Fields::parse("a:1\nb:2 c d:3").unwrap_err();
+        assert_eq!((err.line(), err.columns()), (2, 4..5));
+        assert!(matches!(kind(&err), KvError::Malformed { data } if data == "c"));
+        let fields = Fields::parse("url:http://x a:1:2").unwrap();
+        assert_eq!(fields.get_str("url").unwrap(), "http://x");
+        assert_eq!(fields.get_str("a").unwrap(), "1:2");
+    }
+
+    #[test]
+    fn test_get() {
+        let fields = Fields::parse(RECORD).unwrap();
+        assert_eq!(fields.get::<u8>("a").unwrap(), 1);
+        assert_eq!(fields.get_opt::<u8>("e").unwrap(), None);
+
+        let err = fields.get::<u8>("b").unwrap_err();
+        assert_eq!((err.line(), err.columns()), (1, 6..7));
+        assert_eq!(err.hint(), Some("invalid digit found in string"));
+        assert!(matches!(kind(&err), KvError::InvalidValue { field, .. } if field == "b"));
+        assert!(fields.get_opt::<u8>("b").is_err());
+
+        let err = fields.get_str("e").unwrap_err();
+        assert_eq!((err.line(), err.text()), (1, "a:1 b:x"));
+        assert!(matches!(kind(&err), KvError::MissingField { field } if field == "e"));
+    }
+
+    #[test]
+    fn test_get_checked() {
+        let fields = Fields::parse(RECORD).unwrap();
+        assert_eq!(fields.get_in_range("d", 10..=40).unwrap(), 40);
+        let err = fields.get_in_range("d", 10..=39).unwrap_err();
+        assert_eq!((err.line(), err.columns()), (2, 9..11));
+        assert_eq!(err.hint(), Some("expected a number from 10 to 39"));
+        assert!(matches!(kind(&err), KvError::OutOfRange { .. }));
+        assert!(matches!(
+            kind(&fields.get_in_range("b", 0..=9).unwrap_err()),
+            KvError::InvalidValue { .. }
+        ));
+
+        let re = Regex::new("^#[0-9]+$").unwrap();
+        assert_eq!(fields.get_matching("c", &re, "").unwrap(), "#123");
+        let err = fields.get_matching("a", &re, "expected `#`").unwrap_err();
+        assert_eq!(err.hint(), Some("expected `#`"));
+
+        let err = fields.invalid("c", "too short", "expected more digits");
+        assert_eq!((err.line(), err.columns()), (2, 2..6));
+    }
+}
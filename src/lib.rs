@@ -1,92 +1,540 @@
-use clap::{App, Arg};
+use clap::{App, ArgMatches};
 
 use std::error;
 use std::fmt;
-use std::fs::File;
-use std::io::{self, Read};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::slice;
+use std::time::{Instant, SystemTime};
 
+use rng::Rng;
+
+pub use check::ExpectedAnswers;
+pub use exit::{finish, Error};
+use input::DAY_OPTIONS;
+pub use input::{input_path, InputError, ProgramInput, DEFAULT_INPUT, INPUT_DIR_VAR};
+pub use parse::ParseError;
+pub use report::{Format, Report, Row, Table};
+pub use scaffold::new_day;
+pub use timing::{format_duration, Stats, Timings};
+
+mod check;
+mod describe;
+mod exit;
+mod input;
+mod parse;
+mod report;
+mod scaffold;
+mod timing;
+mod watch;
+
+pub mod differential;
+pub mod examples;
+pub mod graph;
+pub mod grid;
+pub mod kv;
+pub mod records;
+pub mod rng;
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+
+/// A solution to both parts of a day's puzzle.
+///
+/// The input is parsed once and then handed to each part, which returns
+/// `None` if there is no answer for the given input.
+pub trait Solution {
+    type Input;
+    type Answer: fmt::Display;
+    type Error: error::Error + 'static;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error>;
+
+    fn part1(input: &Self::Input) -> Option<Self::Answer>;
+    fn part2(input: &Self::Input) -> Option<Self::Answer>;
+}
+
+/// The answers to both parts of a puzzle, formatted for display.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Answers {
+    pub fn part(&self, part: usize) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// The answers and timings of a solution, or the error parsing its input.
+pub type SolveResult = Result<(Answers, Timings), Error>;
+
+/// Parses the input with the solution `S` and returns the answers to both
+/// parts, or only to `options.part` if given, together with the time each
+/// step took.
+///
+/// The time spent reading the input is not known here and left at zero, as
+/// is the time of a part that is not solved.
+pub fn solve<S: Solution>(input: &str, options: &SolveOptions) -> SolveResult {
+    solve_with::<S>(input, options, |_, _| {})
+}
+
+/// Solves the input like `solve`, after `configure` has applied the options
+/// of a day that has some of its own (see `Day::options`) to the parsed input.
+pub fn solve_with<S: Solution>(
+    input: &str,
+    options: &SolveOptions,
+    configure: fn(&mut S::Input, &SolveOptions),
+) -> SolveResult {
+    let mut timings = Timings::default();
+    let mut answers = Answers {
+        part1: None,
+        part2: None,
+    };
+
+    let start = Instant::now();
+    let mut input = S::parse(input).map_err(Error::parse)?;
+    configure(&mut input, options);
+    timings.parse = start.elapsed();
+
+    if wants(options.part, 1) {
+        let start = Instant::now();
+        answers.part1 = S::part1(&input).map(|x| x.to_string());
+        timings.part1 = start.elapsed();
+    }
+
+    if wants(options.part, 2) {
+        let start = Instant::now();
+        answers.part2 = S::part2(&input).map(|x| x.to_string());
+        timings.part2 = start.elapsed();
+    }
+
+    Ok((answers, timings))
+}
+
+/// Returns true if `n` is the requested part, or if no part was requested.
+fn wants(part: Option<usize>, n: usize) -> bool {
+    part.is_none() || part == Some(n)
+}
+
+/// A solution that can also solve both parts in a single pass over the lines
+/// of its input, holding only a bounded amount of it in memory.
+pub trait StreamingSolution: Solution {
+    fn solve_lines<I>(lines: I) -> Result<[Option<Self::Answer>; 2], Error>
+    where
+        I: Iterator<Item = Result<String, InputError>>;
+}
+
+/// Solves the input one line at a time with the solution `S`.
+///
+/// Reading, parsing, and solving are interleaved, so no timings are measured.
+/// Both parts are solved in the same pass, but only the answer to
+/// `options.part` is returned if given.
+pub fn solve_stream<S: StreamingSolution>(
+    input: &mut ProgramInput,
+    options: &SolveOptions,
+) -> SolveResult {
+    let [part1, part2] = S::solve_lines(input.lines())?;
+    let answers = Answers {
+        part1: part1
+            .filter(|_| wants(options.part, 1))
+            .map(|x| x.to_string()),
+        part2: part2
+            .filter(|_| wants(options.part, 2))
+            .map(|x| x.to_string()),
+    };
+    Ok((answers, Timings::default()))
+}
+
+/// A puzzle solution as seen by the `aoc20` dispatcher.
+///
+/// Each day module provides its puzzle name and description, the default
+/// input, and a `Solver` implementing `Solution`, which are collected in its
+/// `DAY`.
 #[derive(Debug)]
-pub struct ProgramInput {
-    data: Option<String>,
-    print: bool,
-}
-
-impl ProgramInput {
-    pub fn new(name: &str, default: &str) -> Self {
-        let matches = App::new(name)
-            .author("Ben Morgan <neembi@gmail.com")
-            .arg(
-                Arg::with_name("INPUT")
-                    .help("Input file, use - for stdin")
-                    .index(1),
-            )
-            .arg(
-                Arg::with_name("verbose")
-                    .short("v")
-                    .long("verbose")
-                    .multiple(true)
-                    .help("Print the default input used"),
-            )
-            .get_matches();
-
-        if let Some(input) = matches.value_of("INPUT") {
-            if input == "-" {
-                // We will read stdin later.
-                ProgramInput {
-                    data: None,
-                    print: matches.occurrences_of("verbose") > 2,
-                }
-            } else {
-                // Try to read input as a file.
-                let mut f = File::open(input).expect("file not found");
-                let mut contents = String::new();
-                f.read_to_string(&mut contents)
-                    .expect("error reading the file");
-                ProgramInput {
-                    data: Some(contents),
-                    print: matches.occurrences_of("verbose") > 1,
-                }
-            }
+pub struct Day {
+    pub day: usize,
+    pub puzzle: &'static str,
+    /// The puzzle text, in Markdown, which is also the module documentation.
+    pub description: &'static str,
+    pub input: &'static str,
+    /// Solves both parts of the puzzle, or only the given part.
+    pub solve: fn(&str, &SolveOptions) -> SolveResult,
+    /// Solves the puzzle one line at a time, if the day supports it.
+    pub stream: Option<fn(&mut ProgramInput, &SolveOptions) -> SolveResult>,
+    /// Writes a random valid input for the puzzle.
+    pub generate: fn(&mut Rng, &GenerateOptions, &mut dyn Write) -> io::Result<()>,
+    /// The command line options of the day's own, such as `target` for
+    /// `--target`, which are rejected when running other days.
+    pub options: &'static [&'static str],
+}
+
+/// Options for generating a random input with `Day::generate`.
+#[derive(Debug, Clone)]
+pub struct GenerateOptions {
+    /// The number of records, such as lines, passports, or groups.
+    pub count: usize,
+    /// The fraction of records that are invalid, for days where the puzzle
+    /// is about telling valid records apart.
+    pub invalid: f64,
+}
+
+impl Default for GenerateOptions {
+    fn default() -> Self {
+        GenerateOptions {
+            count: 100,
+            invalid: 0.2,
+        }
+    }
+}
+
+/// Options for solving a puzzle with `Day::solve`.
+#[derive(Debug, Clone, Default)]
+pub struct SolveOptions {
+    /// The only part to solve, if `--part` was given.
+    pub part: Option<usize>,
+    /// The sum that the entries must add up to in day 1, if not 2020.
+    pub target: Option<i64>,
+    /// The number of entries that must add up to the target in part two of
+    /// day 1, if not three.
+    pub k: Option<usize>,
+}
+
+impl Day {
+    /// Solves the puzzle for the input, and checks and times the solution as
+    /// requested by the options.
+    pub fn run(&self, input: &mut ProgramInput, options: &Options) -> Result<Report, Error> {
+        let (answers, samples) = if options.stream {
+            let stream = self.stream.ok_or_else(|| {
+                Error::from(format!(
+                    "day {} cannot process its input as a stream",
+                    self.day
+                ))
+            })?;
+            let name = input.name().to_string();
+            let (answers, timings) =
+                stream(input, &options.solve).map_err(|err| locate(err, &name))?;
+            (answers, vec![timings])
         } else {
-            ProgramInput {
-                data: Some(String::from(default.trim())),
-                print: matches.occurrences_of("verbose") > 0,
+            input.to_str()?; // make sure that stdin has been read
+            let read = input.read_time();
+            let name = input.name().to_string();
+            let data = input.to_str()?;
+
+            let mut samples = Vec::with_capacity(options.repeat);
+            let mut answers = None;
+            for _ in 0..options.repeat {
+                let (result, mut timings) =
+                    (self.solve)(data, &options.solve).map_err(|err| locate(err, &name))?;
+                timings.read = read;
+                answers.get_or_insert(result);
+                samples.push(timings);
+            }
+            (answers.unwrap(), samples)
+        };
+
+        Ok(Report {
+            day: self.day,
+            puzzle: self.puzzle,
+            input: input.name().to_string(),
+            part: options.solve.part,
+            answers,
+            expected: options
+                .expected
+                .as_ref()
+                .map(|x| x.answers(self.day, input.name())),
+            timings: if options.time { samples } else { Vec::new() },
+        })
+    }
+}
+
+impl Day {
+    /// Returns a random valid input for the puzzle.
+    pub fn generate_input(&self, rng: &mut Rng, options: &GenerateOptions) -> String {
+        let mut out = Vec::new();
+        (self.generate)(rng, options, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+}
+
+/// Adds the name of the input to the error if it is a `ParseError`.
+fn locate(err: Error, input: &str) -> Error {
+    match err {
+        Error::Parse(err) => match err.downcast::<ParseError>() {
+            Ok(err) => Error::parse(err.in_input(input)),
+            Err(err) => Error::Parse(err),
+        },
+        err => err,
+    }
+}
+
+/// All days that have been solved so far, in order.
+pub const DAYS: &[Day] = &[
+    day01::DAY,
+    day02::DAY,
+    day03::DAY,
+    day04::DAY,
+    day05::DAY,
+    day06::DAY,
+    day07::DAY,
+];
+
+/// Returns the registered day with the given number, if any.
+pub fn day(n: usize) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == n)
+}
+
+/// Runs a single day with the command line arguments from `ProgramInput`.
+///
+/// This is what the `main` function of each day's binary runs.
+pub fn run(day: &Day) -> Result<(), Error> {
+    let matches = App::new(day.puzzle)
+        .author("Ben Morgan <neembi@gmail.com")
+        .args(&ProgramInput::args())
+        .get_matches();
+    run_days(slice::from_ref(day), &matches)
+}
+
+/// Runs each day in turn with the options given in `matches`, which must
+/// contain the arguments from `ProgramInput::args`.
+///
+/// A day given several inputs is run on each of them, and an input that fails
+/// is reported without stopping the others.
+///
+/// With `--watch`, the days are run again whenever an input file changes,
+/// and errors are printed instead of returned; this never returns unless
+/// there is no input file to watch.
+///
+/// Returns the first error, after running all days and inputs if the error
+/// is a part without an answer, an input of several that failed, or answers
+/// that differ from those given with `--check`.
+pub fn run_days(days: &[Day], matches: &ArgMatches) -> Result<(), Error> {
+    for &name in DAY_OPTIONS {
+        if !matches.is_present(name) {
+            continue;
+        }
+        if let Some(day) = days.iter().find(|day| !day.options.contains(&name)) {
+            return Err(format!("--{} is not an option of day {}", name, day.day).into());
+        }
+    }
+    if matches.is_present("describe") {
+        return Ok(describe_days(days, matches)?);
+    }
+    if matches.is_present("generate") {
+        return Ok(generate_day(days, matches)?);
+    }
+
+    let options = Options::from_matches(matches)?;
+    if matches.is_present("watch") {
+        watch_days(days, matches, &options)
+    } else {
+        run_days_once(days, matches, &options)
+    }
+}
+
+/// Prints the description of each day, wrapped to the terminal width, with
+/// only the part given with `--part`.
+fn describe_days(days: &[Day], matches: &ArgMatches) -> Result<(), Box<dyn error::Error>> {
+    let part = matches.value_of("part").map(str::parse).transpose()?;
+    let width = describe::terminal_width();
+    for (i, day) in days.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        print!("{}", describe::describe(day.description, part, width));
+    }
+    Ok(())
+}
+
+/// Writes a random input for the day to stdout, using the seed given with
+/// `--seed` or a new one that is printed to stderr.
+fn generate_day(days: &[Day], matches: &ArgMatches) -> Result<(), Box<dyn error::Error>> {
+    let day = match days {
+        [day] => day,
+        _ => return Err("--generate needs a single day".into()),
+    };
+    let options = GenerateOptions {
+        count: matches.value_of("generate").unwrap().parse()?,
+        invalid: match matches.value_of("invalid") {
+            Some(x) => x.parse()?,
+            None => GenerateOptions::default().invalid,
+        },
+    };
+    let seed = match matches.value_of("seed") {
+        Some(seed) => seed.parse()?,
+        None => {
+            let seed = SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)?
+                .as_nanos() as u64;
+            eprintln!(":: Generating input with seed {}", seed);
+            seed
+        }
+    };
+
+    let stdout = io::stdout();
+    let mut out = io::BufWriter::new(stdout.lock());
+    (day.generate)(&mut Rng::new(seed), &options, &mut out)?;
+    out.flush()?;
+    Ok(())
+}
+
+fn watch_days(days: &[Day], matches: &ArgMatches, options: &Options) -> Result<(), Error> {
+    loop {
+        let mut paths = Vec::new();
+        for day in days {
+            if let Ok(inputs) = ProgramInput::all_from_matches(matches, day) {
+                paths.extend(inputs.iter().filter_map(|x| x.path()).map(PathBuf::from));
             }
         }
+        if paths.is_empty() {
+            return Err("--watch needs an input file to watch".into());
+        }
+
+        // Take the snapshot first, so that changes made while running are
+        // not missed.
+        let snapshot = watch::Snapshot::new(paths);
+        if options.format == Format::Text {
+            print!("\x1b[2J\x1b[H"); // clear the terminal
+            io::stdout().flush()?;
+        }
+        if let Err(err) = run_days_once(days, matches, options) {
+            eprintln!("error: {}", err);
+        }
+        eprintln!(":: Watching for changes, press Ctrl-C to stop...");
+        snapshot.wait_for_change();
     }
+}
 
-    pub fn to_str(&mut self) -> &str {
-        if self.data.is_none() {
-            println!(":: Reading from stdin...");
-            let mut buffer = String::new();
-            io::stdin().read_to_string(&mut buffer).unwrap();
-            self.data = Some(buffer);
+fn run_days_once(days: &[Day], matches: &ArgMatches, options: &Options) -> Result<(), Error> {
+    let mut result = Ok(());
+    let mut passed = true;
+    for (i, day) in days.iter().enumerate() {
+        if i != 0 && options.format == Format::Text {
+            println!();
         }
-        if self.print {
-            println!(":: Program input is:\n{}\n", self.data.as_ref().unwrap());
-            self.print = false;
+
+        let mut inputs = ProgramInput::all_from_matches(matches, day)?;
+        if let [input] = &mut inputs[..] {
+            let report = day.run(input, options)?;
+            passed &= report.passed();
+            match options.format {
+                Format::Text => print!("{}", report),
+                Format::Json => println!("{}", report.to_json()),
+            }
+            if let Some(part) = report.missing_answer() {
+                keep_first(&mut result, Error::NoAnswer { day: day.day, part });
+            }
+        } else {
+            let mut table = Table::new(day, options);
+            for mut input in inputs {
+                let row = day
+                    .run(&mut input, options)
+                    .map_err(|err| (input.name().to_string(), err));
+                table.rows.push(row);
+            }
+            passed &= table.passed();
+            match options.format {
+                Format::Text => print!("{}", table),
+                Format::Json => print!("{}", table.to_json()),
+            }
+            for row in table.rows {
+                match row {
+                    Ok(report) => {
+                        if let Some(part) = report.missing_answer() {
+                            keep_first(&mut result, Error::NoAnswer { day: day.day, part });
+                        }
+                    }
+                    Err((_, err)) => keep_first(&mut result, err),
+                }
+            }
+        }
+    }
+
+    if options.expected.is_some() && options.format == Format::Text {
+        println!();
+        if passed {
+            println!(":: Check PASSED");
+        } else {
+            println!(":: Check FAILED");
         }
-        self.data.as_ref().unwrap().as_str()
+    }
+    if options.expected.is_some() && !passed {
+        keep_first(&mut result, Error::CheckFailed);
+    }
+    result
+}
+
+/// Sets the result to the error, unless it already has an earlier one.
+fn keep_first(result: &mut Result<(), Error>, err: Error) {
+    if result.is_ok() {
+        *result = Err(err);
     }
 }
 
+/// Options that control how `Day::run` runs a solution.
 #[derive(Debug)]
-pub enum ParseError {
-    Malformed { line: String },
+pub struct Options {
+    /// Expected answers to check against, if `--check` was given.
+    pub expected: Option<ExpectedAnswers>,
+    /// Whether to print how long each step took.
+    pub time: bool,
+    /// How often to run the solution when timing it.
+    pub repeat: usize,
+    /// How the reports are printed.
+    pub format: Format,
+    /// Whether to process the input one line at a time.
+    pub stream: bool,
+    /// The part to solve and the options of the day's solution.
+    pub solve: SolveOptions,
 }
 
-impl error::Error for ParseError {
-    fn description(&self) -> &str {
-        match *self {
-            ParseError::Malformed { .. } => "cannot parse line into claim",
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            expected: None,
+            time: false,
+            repeat: 1,
+            format: Format::Text,
+            stream: false,
+            solve: SolveOptions::default(),
         }
     }
 }
 
-impl fmt::Display for ParseError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match *self {
-            ParseError::Malformed { ref line } => write!(f, "cannot parse line: {}", line),
-        }
+impl Options {
+    pub fn from_matches(matches: &ArgMatches) -> Result<Self, Box<dyn error::Error>> {
+        let expected = if matches.is_present("check") {
+            Some(ExpectedAnswers::from_file(
+                matches.value_of("answers").unwrap(),
+            )?)
+        } else {
+            None
+        };
+        let repeat = match matches.value_of("repeat") {
+            Some(n) => n.parse()?,
+            None => 1,
+        };
+        Ok(Options {
+            expected,
+            time: matches.is_present("time") || matches.is_present("repeat"),
+            repeat,
+            format: matches.value_of("format").unwrap_or("text").parse()?,
+            stream: matches.is_present("stream"),
+            solve: SolveOptions {
+                part: matches.value_of("part").map(|x| x.parse()).transpose()?,
+                target: matches.value_of("target").map(|x| x.parse()).transpose()?,
+                k: matches.value_of("k").map(|x| x.parse()).transpose()?,
+            },
+        })
     }
 }
@@ -0,0 +1,74 @@
+use aoc20::{Error, ProgramInput, DAYS};
+use clap::{App, AppSettings, Arg, SubCommand};
+
+use std::env;
+use std::process::ExitCode;
+use std::slice;
+
+fn main() -> ExitCode {
+    aoc20::finish(run())
+}
+
+fn run() -> Result<(), Error> {
+    let matches = App::new("aoc20")
+        .author("Ben Morgan <neembi@gmail.com")
+        .about("Advent of Code 2020 solutions")
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .subcommand(
+            SubCommand::with_name("run")
+                .about("Run the solution for a day")
+                .arg(
+                    Arg::with_name("DAY")
+                        .help("Day to run, or all")
+                        .required(true),
+                )
+                .args(&ProgramInput::args()),
+        )
+        .subcommand(
+            SubCommand::with_name("new")
+                .about("Create the binary and module for a new day")
+                .arg(Arg::with_name("DAY").help("Day to create").required(true))
+                .arg(
+                    Arg::with_name("TITLE")
+                        .help("Title of the puzzle")
+                        .required(true),
+                )
+                .arg(
+                    Arg::with_name("bin")
+                        .long("bin")
+                        .value_name("NAME")
+                        .help("Name of the binary [default: first word of the title]"),
+                ),
+        )
+        .get_matches();
+
+    if let Some(matches) = matches.subcommand_matches("new") {
+        let which = matches.value_of("DAY").unwrap();
+        let day = which
+            .parse()
+            .map_err(|_| Error::from(format!("invalid day {}", which)))?;
+        let title = matches.value_of("TITLE").unwrap();
+        let root = env::current_dir()?;
+        for path in aoc20::new_day(&root, day, title, matches.value_of("bin"))? {
+            let path = path.strip_prefix(&root).unwrap_or(&path);
+            println!(":: Wrote {}", path.display());
+        }
+    }
+
+    if let Some(matches) = matches.subcommand_matches("run") {
+        let which = matches.value_of("DAY").unwrap();
+        let days = if which == "all" {
+            if matches.is_present("INPUT") {
+                return Err("INPUT cannot be used together with all".into());
+            }
+            DAYS
+        } else {
+            match which.parse().ok().and_then(aoc20::day) {
+                Some(day) => slice::from_ref(day),
+                None => return Err(format!("no solution for day {}", which).into()),
+            }
+        };
+        aoc20::run_days(days, matches)?;
+    }
+    Ok(())
+}
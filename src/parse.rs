@@ -0,0 +1,201 @@
+//! Errors in the input that point at where they occurred.
+
+use std::error;
+use std::fmt;
+use std::ops::Range;
+
+/// An error in the input, pointing at the offending part of a line.
+///
+/// Parsers create the error for the line they are looking at, and callers
+/// that know more about where the line came from add the line number and the
+/// name of the input. It is displayed like a compiler diagnostic:
+///
+/// ```text
+/// invalid letter in password policy: "#"
+///  --> inputs/day02.txt:3:5
+///   |
+/// 3 | 1-3 #: abcde
+///   |     ^ expected a single lowercase letter
+/// ```
+#[derive(Debug)]
+pub struct ParseError {
+    input: Option<String>,
+    line: usize,
+    columns: Range<usize>,
+    text: String,
+    kind: Box<dyn error::Error + Send + Sync>,
+    hint: Option<String>,
+}
+
+impl ParseError {
+    /// Returns an error of the given kind that spans the whole line of text,
+    /// which is the first line of the input until `at_line` is used.
+    pub fn new<E>(text: &str, kind: E) -> Self
+    where
+        E: Into<Box<dyn error::Error + Send + Sync>>,
+    {
+        ParseError {
+            input: None,
+            line: 1,
+            columns: 0..text.chars().count(),
+            text: text.into(),
+            kind: kind.into(),
+            hint: None,
+        }
+    }
+
+    /// Returns an error of the given kind that points at `part`, which must
+    /// be a slice of the line of text.
+    pub fn at<E>(text: &str, part: &str, kind: E) -> Self
+    where
+        E: Into<Box<dyn error::Error + Send + Sync>>,
+    {
+        let start = offset(text, part);
+        Self::new(text, kind).with_columns(start..start + part.chars().count())
+    }
+
+    /// Sets the 0-based range of characters in the line that the error
+    /// points at.
+    pub fn with_columns(mut self, columns: Range<usize>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Moves the error from `part` to the enclosing line of text, for errors
+    /// created by a parser that only saw part of the line.
+    pub fn within(mut self, line: &str, part: &str) -> Self {
+        let start = offset(line, part);
+        self.columns = self.columns.start + start..self.columns.end + start;
+        self.text = line.into();
+        self
+    }
+
+    pub fn with_hint<S: Into<String>>(mut self, hint: S) -> Self {
+        self.hint = Some(hint.into());
+        self
+    }
+
+    /// Sets the 1-based line number of the line in the input.
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    /// Moves the error down by the given number of lines, for errors in part
+    /// of the input that does not start at the first line.
+    pub fn shift_lines(mut self, lines: usize) -> Self {
+        self.line += lines;
+        self
+    }
+
+    /// Sets the name of the input, such as the path of the input file.
+    pub fn in_input(mut self, input: &str) -> Self {
+        self.input = Some(input.into());
+        self
+    }
+
+    pub fn input(&self) -> Option<&str> {
+        self.input.as_deref()
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn columns(&self) -> Range<usize> {
+        self.columns.clone()
+    }
+
+    /// Returns the line of text that contains the error.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Returns what is wrong with the input.
+    pub fn kind(&self) -> &(dyn error::Error + Send + Sync + 'static) {
+        &*self.kind
+    }
+
+    pub fn hint(&self) -> Option<&str> {
+        self.hint.as_deref()
+    }
+}
+
+/// Returns the column at which `part`, a slice of `text`, starts.
+fn offset(text: &str, part: &str) -> usize {
+    let start = (part.as_ptr() as usize)
+        .checked_sub(text.as_ptr() as usize)
+        .filter(|x| x + part.len() <= text.len())
+        .expect("part is not a slice of the text");
+    text[..start].chars().count()
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        writeln!(f, "{}", self.kind)?;
+        writeln!(
+            f,
+            "{}--> {}:{}:{}",
+            gutter,
+            self.input().unwrap_or("<input>"),
+            self.line,
+            self.columns.start + 1
+        )?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", number, self.text)?;
+        write!(
+            f,
+            "{} | {}{}",
+            gutter,
+            " ".repeat(self.columns.start),
+            "^".repeat(self.columns.len().max(1))
+        )?;
+        if let Some(hint) = &self.hint {
+            write!(f, " {}", hint)?;
+        }
+        Ok(())
+    }
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&*self.kind)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let text = "1-3 #: abcde";
+        let err = ParseError::at(text, &text[4..5], "invalid password policy")
+            .with_hint("expected a single lowercase letter")
+            .at_line(12)
+            .in_input("inputs/day02.txt");
+
+        assert_eq!(err.columns(), 4..5);
+        assert_eq!(err.input(), Some("inputs/day02.txt"));
+        assert_eq!(
+            err.to_string(),
+            "invalid password policy
+  --> inputs/day02.txt:12:5
+   |
+12 | 1-3 #: abcde
+   |     ^ expected a single lowercase letter"
+        );
+    }
+
+    #[test]
+    fn test_within() {
+        let line = "1-3 a: abcde";
+        let pass = &line[7..];
+        let err = ParseError::at(pass, &pass[1..3], "too short").within(line, pass);
+
+        assert_eq!(err.text(), line);
+        assert_eq!(err.columns(), 8..10);
+    }
+}
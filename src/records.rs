@@ -0,0 +1,130 @@
+//! Records of several lines separated by blank lines, such as passports or
+//! the answers of a group.
+//!
+//! ```
+//! use aoc20::records::records;
+//!
+//! let input = "a b\r\nc\r\n\r\n  \r\n\r\nd e  \n";
+//! let records: Vec<_> = records(input).collect();
+//! assert_eq!(records.len(), 2);
+//! assert_eq!(records[0].lines().collect::<Vec<_>>(), ["a b", "c"]);
+//! assert_eq!(records[1].line(), 6);
+//! assert_eq!(records[1].tokens().collect::<Vec<_>>(), ["d", "e"]);
+//! ```
+
+use crate::ParseError;
+
+use std::iter::Enumerate;
+use std::str::{FromStr, Lines};
+
+/// A record of consecutive non-blank lines, without trailing whitespace.
+///
+/// Leading whitespace is kept, so that the columns of parse errors match the
+/// input; solutions that read the lines themselves must skip it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record {
+    line: usize,
+    text: String,
+}
+
+impl Record {
+    /// Returns the 1-based line number of the first line of the record in
+    /// the input.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Returns the lines of the record, separated by `\n`.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = &str> {
+        self.text.lines()
+    }
+
+    /// Returns the words of the record, which are separated by whitespace
+    /// within and across lines.
+    pub fn tokens(&self) -> impl Iterator<Item = &str> {
+        self.text.split_whitespace()
+    }
+
+    /// Parses the text of the record, with the line of an error counted from
+    /// the start of the input instead of the start of the record.
+    pub fn parse<T>(&self) -> Result<T, ParseError>
+    where
+        T: FromStr<Err = ParseError>,
+    {
+        self.text
+            .parse()
+            .map_err(|err: ParseError| err.shift_lines(self.line - 1))
+    }
+}
+
+/// An iterator over the records of an input; see `records`.
+#[derive(Debug)]
+pub struct Records<'a> {
+    lines: Enumerate<Lines<'a>>,
+}
+
+/// Returns the records of the input, which are separated by one or more
+/// blank lines.
+///
+/// Both `\n` and `\r\n` line endings are accepted, and lines that contain
+/// only whitespace count as blank.
+pub fn records(input: &str) -> Records<'_> {
+    Records {
+        lines: input.lines().enumerate(),
+    }
+}
+
+impl Iterator for Records<'_> {
+    type Item = Record;
+
+    fn next(&mut self) -> Option<Record> {
+        let (i, first) = self.lines.find(|(_, x)| !x.trim().is_empty())?;
+        let mut text = first.trim_end().to_string();
+        for (_, line) in &mut self.lines {
+            let line = line.trim_end();
+            if line.trim_start().is_empty() {
+                break;
+            }
+            text.push('\n');
+            text += line;
+        }
+        Some(Record { line: i + 1, text })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_records() {
+        let input = "\n\nab\ncd\t\n\n\n\nef\n";
+        let records: Vec<_> = records(input).collect();
+        assert_eq!(
+            records,
+            [
+                Record {
+                    line: 3,
+                    text: "ab\ncd".into()
+                },
+                Record {
+                    line: 8,
+                    text: "ef".into()
+                },
+            ]
+        );
+        assert_eq!(super::records("").count(), 0);
+        assert_eq!(super::records(" \n\t\n").count(), 0);
+    }
+
+    #[test]
+    fn test_parse() {
+        let record = records("#\n\n.\nx").nth(1).unwrap();
+        let err = record.parse::<crate::day03::Map>().unwrap_err();
+        assert_eq!((err.line(), err.columns()), (4, 0..1));
+    }
+}
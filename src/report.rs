@@ -0,0 +1,488 @@
+//! The outcome of running a day, printed as text or JSON.
+//!
+//! Every day uses the same JSON schema, with one object per run:
+//!
+//! ```text
+//! {
+//!   "day": 7,
+//!   "puzzle": "Handy Haversacks",
+//!   "input": "default",
+//!   "part1": "246",
+//!   "part2": "2976",
+//!   "expected": {"part1": "246", "part2": null},
+//!   "passed": true,
+//!   "timings": {
+//!     "runs": 3,
+//!     "read": {"min_ns": 1000, "median_ns": 1000, "max_ns": 1000},
+//!     "parse": {...},
+//!     "part1": {...},
+//!     "part2": {...}
+//!   }
+//! }
+//! ```
+//!
+//! Answers are strings, or null if there is no answer. The `expected` and
+//! `passed` fields are only present with `--check`, and `timings` only with
+//! `--time`. With `--part`, the fields of the other part are left out. The
+//! object is printed on a single line.
+//!
+//! When a day is run on several inputs, an input that fails is reported as
+//! `{"day": 7, "puzzle": "Handy Haversacks", "input": "bob.txt", "error": "..."}`
+//! instead, and the text output is a table with one row per input.
+
+use crate::{format_duration, timing, Answers, Day, Options, ParseError, Stats, Timings};
+
+use std::fmt::{self, Write};
+use std::str::FromStr;
+use std::time::Duration;
+use thiserror::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Error, Debug)]
+#[error("unknown output format: {value:?}")]
+pub struct FormatError {
+    value: String,
+}
+
+impl FromStr for Format {
+    type Err = FormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(FormatError { value: s.into() }),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Report {
+    pub day: usize,
+    pub puzzle: &'static str,
+    pub input: String,
+    /// The only part that was solved, if not both.
+    pub part: Option<usize>,
+    pub answers: Answers,
+    /// The expected answers, if the answers were checked.
+    pub expected: Option<Answers>,
+    /// The timings of each run, if the solution was timed.
+    pub timings: Vec<Timings>,
+}
+
+impl Report {
+    /// Returns the parts that were solved.
+    pub fn parts(&self) -> Vec<usize> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+
+    /// Returns the first solved part that has no answer, if any.
+    pub fn missing_answer(&self) -> Option<usize> {
+        self.parts()
+            .into_iter()
+            .find(|&part| self.answers.part(part).is_none())
+    }
+
+    /// Returns false if any answer does not match a known expected answer.
+    pub fn passed(&self) -> bool {
+        match &self.expected {
+            Some(expected) => self
+                .parts()
+                .into_iter()
+                .all(|part| match expected.part(part) {
+                    Some(want) => self.answers.part(part) == Some(want),
+                    None => true,
+                }),
+            None => true,
+        }
+    }
+
+    fn stats(&self, f: fn(&Timings) -> Duration) -> Stats {
+        Stats::new(&timing::samples(&self.timings, f))
+    }
+
+    pub fn to_json(&self) -> String {
+        let mut s = String::new();
+        write!(
+            s,
+            r#"{{"day":{},"puzzle":{},"input":{}"#,
+            self.day,
+            json_string(self.puzzle),
+            json_string(&self.input),
+        )
+        .unwrap();
+        for part in self.parts() {
+            write!(
+                s,
+                r#","part{}":{}"#,
+                part,
+                json_answer(self.answers.part(part))
+            )
+            .unwrap();
+        }
+        if let Some(expected) = &self.expected {
+            s.push_str(r#","expected":{"#);
+            for (i, part) in self.parts().into_iter().enumerate() {
+                let sep = if i == 0 { "" } else { "," };
+                write!(
+                    s,
+                    r#"{}"part{}":{}"#,
+                    sep,
+                    part,
+                    json_answer(expected.part(part))
+                )
+                .unwrap();
+            }
+            write!(s, r#"}},"passed":{}"#, self.passed()).unwrap();
+        }
+        if !self.timings.is_empty() {
+            write!(
+                s,
+                r#","timings":{{"runs":{},"read":{},"parse":{}"#,
+                self.timings.len(),
+                json_stats(&self.stats(|x| x.read)),
+                json_stats(&self.stats(|x| x.parse)),
+            )
+            .unwrap();
+            for part in self.parts() {
+                let stats = self.stats(PARTS[part - 1]);
+                write!(s, r#","part{}":{}"#, part, json_stats(&stats)).unwrap();
+            }
+            s.push('}');
+        }
+        s.push('}');
+        s
+    }
+}
+
+/// Returns the time taken by each part.
+const PARTS: [fn(&Timings) -> Duration; 2] = [|x| x.part1, |x| x.part2];
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Day {}: {}", self.day, self.puzzle)?;
+        for part in self.parts() {
+            let answer = self.answers.part(part);
+            match answer {
+                Some(answer) => write!(f, ":: Answer {} is {}", part, answer)?,
+                None => write!(f, ":: There is no answer {}", part)?,
+            }
+            match self.expected.as_ref().map(|x| x.part(part)) {
+                None => writeln!(f)?,
+                Some(None) => writeln!(f, " [UNKNOWN]")?,
+                Some(Some(want)) if Some(want) == answer => writeln!(f, " [PASS]")?,
+                Some(Some(want)) => writeln!(f, " [FAIL: expected {}]", want)?,
+            }
+        }
+        if !self.timings.is_empty() {
+            write_time(f, "read input", &self.stats(|x| x.read))?;
+            write_time(f, "parse input", &self.stats(|x| x.parse))?;
+            for part in self.parts() {
+                let what = format!("solve part {}", part);
+                write_time(f, &what, &self.stats(PARTS[part - 1]))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The reports of running a day on several inputs, displayed as a table with
+/// one row per input.
+#[derive(Debug)]
+pub struct Table {
+    pub day: usize,
+    pub puzzle: &'static str,
+    /// The only part that was solved, if not both.
+    pub part: Option<usize>,
+    pub time: bool,
+    pub check: bool,
+    pub rows: Vec<Row>,
+}
+
+/// The report of an input, or the name of the input and its error.
+pub type Row = Result<Report, (String, crate::Error)>;
+
+impl Table {
+    pub fn new(day: &Day, options: &Options) -> Self {
+        Table {
+            day: day.day,
+            puzzle: day.puzzle,
+            part: options.solve.part,
+            time: options.time,
+            check: options.expected.is_some(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// Returns false if any input failed or any answer did not match.
+    pub fn passed(&self) -> bool {
+        self.rows.iter().all(|row| match row {
+            Ok(report) => report.passed(),
+            Err(_) => false,
+        })
+    }
+
+    fn parts(&self) -> Vec<usize> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+
+    /// Returns one JSON object per input, one per line.
+    pub fn to_json(&self) -> String {
+        let mut s = String::new();
+        for row in &self.rows {
+            match row {
+                Ok(report) => s.push_str(&report.to_json()),
+                Err((input, err)) => write!(
+                    s,
+                    r#"{{"day":{},"puzzle":{},"input":{},"error":{}}}"#,
+                    self.day,
+                    json_string(self.puzzle),
+                    json_string(input),
+                    json_string(&err.to_string()),
+                )
+                .unwrap(),
+            }
+            s.push('\n');
+        }
+        s
+    }
+
+    fn header(&self) -> Vec<String> {
+        let mut header = vec!["Input".to_string()];
+        for part in self.parts() {
+            header.push(format!("Part {}", part));
+        }
+        if self.time {
+            header.push("Parse".into());
+            for part in self.parts() {
+                header.push(format!("Time {}", part));
+            }
+        }
+        if self.check {
+            header.push("Check".into());
+        }
+        header
+    }
+
+    fn cells(&self, report: &Report) -> Vec<String> {
+        let mut cells = vec![report.input.clone()];
+        for part in self.parts() {
+            cells.push(report.answers.part(part).unwrap_or("-").to_string());
+        }
+        if self.time {
+            cells.push(format_duration(report.stats(|x| x.parse).median));
+            for part in self.parts() {
+                cells.push(format_duration(report.stats(PARTS[part - 1]).median));
+            }
+        }
+        if self.check {
+            cells.push(if report.passed() { "PASS" } else { "FAIL" }.into());
+        }
+        cells
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let header = self.header();
+        let rows: Vec<Vec<String>> = self
+            .rows
+            .iter()
+            .map(|row| match row {
+                Ok(report) => self.cells(report),
+                Err((input, err)) => vec![input.clone(), format!("error: {}", summary(err))],
+            })
+            .collect();
+
+        // The error message of a failed input is not aligned with the other
+        // columns, so it does not widen them.
+        let mut widths: Vec<usize> = header.iter().map(|x| x.chars().count()).collect();
+        for row in rows.iter().filter(|x| x.len() == header.len()) {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+        widths[0] = rows
+            .iter()
+            .map(|x| x[0].chars().count())
+            .fold(widths[0], usize::max);
+
+        writeln!(f, "Day {}: {}", self.day, self.puzzle)?;
+        for row in Some(&header).into_iter().chain(&rows) {
+            let last = row.len() - 1;
+            for (i, cell) in row.iter().enumerate() {
+                if i == last {
+                    writeln!(f, "{}", cell)?;
+                } else {
+                    write!(f, "{:width$}  ", cell, width = widths[i])?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns the error on a single line, with the position of a parse error.
+fn summary(err: &crate::Error) -> String {
+    let parse_error = match err {
+        crate::Error::Parse(err) => err.downcast_ref::<ParseError>(),
+        _ => None,
+    };
+    match parse_error {
+        Some(err) => format!(
+            "{} (line {}, column {})",
+            err.kind(),
+            err.line(),
+            err.columns().start + 1
+        ),
+        None => err.to_string().lines().collect::<Vec<_>>().join(" "),
+    }
+}
+
+fn write_time(f: &mut fmt::Formatter, what: &str, stats: &Stats) -> fmt::Result {
+    if stats.runs == 1 || stats.min == stats.max {
+        writeln!(
+            f,
+            ":: Time to {} is {}",
+            what,
+            format_duration(stats.median)
+        )
+    } else {
+        writeln!(
+            f,
+            ":: Time to {} is {} (min {}, max {}, {} runs)",
+            what,
+            format_duration(stats.median),
+            format_duration(stats.min),
+            format_duration(stats.max),
+            stats.runs
+        )
+    }
+}
+
+fn json_answer(answer: Option<&str>) -> String {
+    match answer {
+        Some(answer) => json_string(answer),
+        None => "null".into(),
+    }
+}
+
+fn json_stats(stats: &Stats) -> String {
+    format!(
+        r#"{{"min_ns":{},"median_ns":{},"max_ns":{}}}"#,
+        stats.min.as_nanos(),
+        stats.median.as_nanos(),
+        stats.max.as_nanos()
+    )
+}
+
+/// Returns the string as a quoted JSON string.
+pub(crate) fn json_string(s: &str) -> String {
+    let mut result = String::with_capacity(s.len() + 2);
+    result.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(result, "\\u{:04x}", c as u32).unwrap(),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report() -> Report {
+        Report {
+            day: 7,
+            puzzle: "Handy Haversacks",
+            input: "default".into(),
+            part: None,
+            answers: Answers {
+                part1: Some("246".into()),
+                part2: None,
+            },
+            expected: None,
+            timings: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_to_json() {
+        let mut report = report();
+        assert_eq!(
+            report.to_json(),
+            r#"{"day":7,"puzzle":"Handy Haversacks","input":"default","part1":"246","part2":null}"#
+        );
+
+        report.expected = Some(Answers {
+            part1: Some("247".into()),
+            part2: None,
+        });
+        assert!(!report.passed());
+        assert!(report
+            .to_json()
+            .ends_with(r#""expected":{"part1":"247","part2":null},"passed":false}"#));
+
+        report.part = Some(2);
+        assert!(report.passed());
+        assert_eq!(
+            report.to_json(),
+            r#"{"day":7,"puzzle":"Handy Haversacks","input":"default","part2":null,"expected":{"part2":null},"passed":true}"#
+        );
+    }
+
+    #[test]
+    fn test_table() {
+        let mut table = Table {
+            day: 7,
+            puzzle: "Handy Haversacks",
+            part: None,
+            time: false,
+            check: false,
+            rows: Vec::new(),
+        };
+        table.rows.push(Ok(report()));
+        let err = ParseError::new("light red bags", "invalid rule").at_line(3);
+        table
+            .rows
+            .push(Err(("inputs/day07-bob.txt".into(), err.into())));
+
+        assert!(!table.passed());
+        assert_eq!(
+            table.to_string(),
+            "Day 7: Handy Haversacks
+Input                 Part 1  Part 2
+default               246     -
+inputs/day07-bob.txt  error: invalid rule (line 3, column 1)
+"
+        );
+        assert!(table
+            .to_json()
+            .ends_with(r#""input":"inputs/day07-bob.txt","error":"invalid rule\n --> <input>:3:1\n  |\n3 | light red bags\n  | ^^^^^^^^^^^^^^"}
+"#));
+    }
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("a \"b\"\n\u{1}"), r#""a \"b\"\n\u0001""#);
+    }
+}
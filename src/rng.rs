@@ -0,0 +1,92 @@
+//! A small seeded random number generator for generating inputs.
+
+use std::ops::Range;
+
+/// A SplitMix64 random number generator, which produces the same numbers for
+/// the same seed on every platform.
+///
+/// ```
+/// use aoc20::rng::Rng;
+///
+/// let mut rng = Rng::new(7);
+/// let x = rng.range(1..7);
+/// assert!((1..7).contains(&x));
+/// assert_eq!(Rng::new(7).range(1..7), x);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Returns a number in the range, which must not be empty.
+    pub fn range(&mut self, range: Range<usize>) -> usize {
+        assert!(!range.is_empty(), "empty range");
+        let len = (range.end - range.start) as u64;
+        // Rejecting the last incomplete multiple of len avoids a bias
+        // towards small numbers.
+        let zone = u64::MAX - u64::MAX % len;
+        loop {
+            let x = self.next_u64();
+            if x < zone {
+                return range.start + (x % len) as usize;
+            }
+        }
+    }
+
+    /// Returns true with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    /// Returns a random element of the slice, which must not be empty.
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..items.len())]
+    }
+
+    /// Shuffles the slice in place.
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0..i + 1));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rng() {
+        // The first output of SplitMix64 for seed 0.
+        assert_eq!(Rng::new(0).next_u64(), 0xe220_a839_7b1d_cdaf);
+
+        let mut rng = Rng::new(42);
+        let mut seen = [false; 6];
+        for _ in 0..1000 {
+            seen[rng.range(10..16) - 10] = true;
+        }
+        assert!(seen.iter().all(|x| *x));
+
+        let mut items: Vec<_> = (0..20).collect();
+        rng.shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort_unstable();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+
+        assert!(!rng.chance(0.0));
+        assert!(rng.chance(1.0));
+    }
+}
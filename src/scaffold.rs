@@ -0,0 +1,314 @@
+//! Scaffolding for the solution of a new day.
+//!
+//! `new_day` writes the binary, module, and description of a day from
+//! templates that follow the conventions of the existing days, and registers
+//! the module in `src/lib.rs`, so that `aoc20 run` finds it straight away.
+
+use std::error;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Template for `src/bin/NN_<name>.rs`.
+const BIN_TEMPLATE: &str = r#"//! Runs the solution for day {day}; see `aoc20::day{day:02}` for the puzzle.
+
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    aoc20::finish(aoc20::run(&aoc20::day{day:02}::DAY))
+}
+"#;
+
+/// Template for `src/dayNN.md`.
+const DESCRIPTION_TEMPLATE: &str = r#"# Day {day}: {title}
+
+Paste the puzzle description here, and annotate each worked example with its
+expected answers:
+
+<!-- example: part1=none part2=none -->
+```text
+example
+```
+
+## Part Two
+
+Paste the second part of the puzzle description here.
+"#;
+
+/// Template for `src/dayNN.rs`.
+const DAY_TEMPLATE: &str = r#"#![doc = include_str!("day{day:02}.md")]
+
+use crate::rng::Rng;
+use crate::{Day, GenerateOptions, ParseError, Solution};
+use std::io::{self, Write};
+use thiserror::Error;
+
+pub struct Solver;
+
+impl Solution for Solver {
+    type Input = Vec<String>;
+    type Answer = usize;
+    type Error = ParseError;
+
+    fn parse(input: &str) -> Result<Self::Input, Self::Error> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, x)| {
+                if x.trim().is_empty() {
+                    Err(ParseError::new(x, {error}::Empty).at_line(i + 1))
+                } else {
+                    Ok(x.to_string())
+                }
+            })
+            .collect()
+    }
+
+    fn part1(_input: &Self::Input) -> Option<usize> {
+        None
+    }
+
+    fn part2(_input: &Self::Input) -> Option<usize> {
+        None
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum {error} {
+    #[error("empty line")]
+    Empty,
+}
+
+impl From<{error}> for crate::Error {
+    fn from(err: {error}) -> Self {
+        crate::Error::parse(err)
+    }
+}
+
+/// Writes a random valid input with `options.count` lines.
+pub fn generate(rng: &mut Rng, options: &GenerateOptions, out: &mut dyn Write) -> io::Result<()> {
+    for _ in 0..options.count {
+        let len = rng.range(1..10);
+        let word: String = (0..len)
+            .map(|_| (b'a' + rng.range(0..26) as u8) as char)
+            .collect();
+        writeln!(out, "{}", word)?;
+    }
+    Ok(())
+}
+
+pub const DAY: Day = Day {
+    day: {day},
+    puzzle: PUZZLE,
+    description: DESCRIPTION,
+    input: INPUT,
+    solve: crate::solve::<Solver>,
+    stream: None,
+    generate,
+    options: &[],
+};
+
+pub const PUZZLE: &str = "{title}";
+
+pub const DESCRIPTION: &str = include_str!("day{day:02}.md");
+
+pub const INPUT: &str = r"
+";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate() {
+        let options = GenerateOptions::default();
+        let input = DAY.generate_input(&mut Rng::new(1), &options);
+        let lines = Solver::parse(&input).unwrap();
+        assert_eq!(lines.len(), options.count);
+    }
+
+    #[test]
+    fn test_examples() {
+        crate::examples::assert_examples(&DAY);
+    }
+}
+"#;
+
+/// Writes the binary, module, and description of a new day into the crate at
+/// `root`, and registers the module in `src/lib.rs`.
+///
+/// The binary is named after `name`, or the first word of the title if it
+/// is `None`. No file is written if the binary or module already exists or
+/// the day is already registered. Returns the paths that were written.
+pub fn new_day(
+    root: &Path,
+    day: usize,
+    title: &str,
+    name: Option<&str>,
+) -> Result<Vec<PathBuf>, Box<dyn error::Error>> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("invalid day {}, expected 1 to 25", day).into());
+    }
+    if title.trim().is_empty() || title.contains(['"', '\\']) {
+        return Err(format!("invalid title {:?}", title).into());
+    }
+    let name = match name {
+        Some(name) => name.to_string(),
+        None => slug(title),
+    };
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_lowercase() || c == '_') {
+        return Err(format!(
+            "invalid binary name {:?}, expected lowercase letters and underscores",
+            name
+        )
+        .into());
+    }
+
+    let lib_path = root.join("src/lib.rs");
+    let bin_path = root.join(format!("src/bin/{:02}_{}.rs", day, name));
+    let day_path = root.join(format!("src/day{:02}.rs", day));
+    let description_path = root.join(format!("src/day{:02}.md", day));
+    let lib = fs::read_to_string(&lib_path)
+        .map_err(|err| format!("cannot read {}: {}", lib_path.display(), err))?;
+    for path in &[&bin_path, &day_path, &description_path] {
+        if path.exists() {
+            return Err(format!("{} already exists", path.display()).into());
+        }
+    }
+    let lib = register(&lib, day)?;
+
+    let fill = |template: &str| {
+        template
+            .replace("{day:02}", &format!("{:02}", day))
+            .replace("{day}", &day.to_string())
+            .replace("{title}", title.trim())
+            .replace("{error}", &format!("{}Error", camel_case(&name)))
+    };
+    fs::create_dir_all(root.join("src/bin"))?;
+    fs::write(&bin_path, fill(BIN_TEMPLATE))?;
+    fs::write(&day_path, fill(DAY_TEMPLATE))?;
+    fs::write(&description_path, fill(DESCRIPTION_TEMPLATE))?;
+    fs::write(&lib_path, lib)?;
+    Ok(vec![bin_path, day_path, description_path, lib_path])
+}
+
+/// Returns the first word of the title in lowercase, such as `handy` for
+/// "Handy Haversacks".
+fn slug(title: &str) -> String {
+    title
+        .split_whitespace()
+        .next()
+        .unwrap_or("")
+        .chars()
+        .filter(char::is_ascii_alphabetic)
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+fn camel_case(name: &str) -> String {
+    name.split('_')
+        .filter(|x| !x.is_empty())
+        .map(|x| x[..1].to_uppercase() + &x[1..])
+        .collect()
+}
+
+/// Returns `lib` with `pub mod dayNN;` and `dayNN::DAY` added to the module
+/// declarations and `DAYS`, keeping both in order.
+fn register(lib: &str, day: usize) -> Result<String, Box<dyn error::Error>> {
+    let module = format!("pub mod day{:02};", day);
+    let entry = format!("    day{:02}::DAY,", day);
+    let mut lines: Vec<&str> = lib.lines().collect();
+    if lines.contains(&module.as_str()) || lines.contains(&entry.as_str()) {
+        return Err(format!("day {} is already registered in src/lib.rs", day).into());
+    }
+
+    let days_start = lines
+        .iter()
+        .position(|x| x.starts_with("pub const DAYS: &[Day] = &["))
+        .ok_or("cannot find DAYS in src/lib.rs")?;
+    let days_end = days_start
+        + lines[days_start..]
+            .iter()
+            .position(|x| *x == "];")
+            .ok_or("cannot find the end of DAYS in src/lib.rs")?;
+    let at = insert_point(&lines[days_start + 1..days_end], &entry);
+    lines.insert(days_start + 1 + at, &entry);
+
+    let modules: Vec<usize> = (0..lines.len())
+        .filter(|&i| lines[i].starts_with("pub mod day"))
+        .collect();
+    let (first, last) = match (modules.first(), modules.last()) {
+        (Some(&first), Some(&last)) => (first, last),
+        _ => return Err("cannot find the day modules in src/lib.rs".into()),
+    };
+    let at = insert_point(&lines[first..=last], &module);
+    lines.insert(first + at, &module);
+
+    let mut lib = lines.join("\n");
+    lib.push('\n');
+    Ok(lib)
+}
+
+/// Returns the index before the first line that sorts after `line`.
+fn insert_point(lines: &[&str], line: &str) -> usize {
+    lines.iter().position(|x| *x > line).unwrap_or(lines.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    const LIB: &str = "pub mod day01;
+pub mod day03;
+
+pub const DAYS: &[Day] = &[
+    day01::DAY,
+    day03::DAY,
+];
+";
+
+    #[test]
+    fn test_register() {
+        assert_eq!(
+            register(LIB, 2).unwrap(),
+            "pub mod day01;
+pub mod day02;
+pub mod day03;
+
+pub const DAYS: &[Day] = &[
+    day01::DAY,
+    day02::DAY,
+    day03::DAY,
+];
+"
+        );
+        assert!(register(LIB, 12)
+            .unwrap()
+            .contains("day03::DAY,\n    day12::DAY,\n];"));
+        assert!(register(LIB, 3).is_err());
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = env::temp_dir().join(format!("aoc20-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join("src/lib.rs"), LIB).unwrap();
+
+        let paths = new_day(&root, 8, "Handheld Halting", None).unwrap();
+        assert!(paths[0].ends_with("src/bin/08_handheld.rs"));
+        let module = fs::read_to_string(&paths[1]).unwrap();
+        assert!(module.starts_with("#![doc = include_str!(\"day08.md\")]\n"));
+        assert!(module.contains("pub enum HandheldError {"));
+        let description = fs::read_to_string(&paths[2]).unwrap();
+        assert!(description.starts_with("# Day 8: Handheld Halting\n"));
+        assert!(fs::read_to_string(&paths[3])
+            .unwrap()
+            .contains("day03::DAY,\n    day08::DAY,\n];"));
+
+        // Nothing is overwritten, not even with another binary name.
+        let err = new_day(&root, 8, "Handheld Halting", Some("halting")).unwrap_err();
+        assert!(err.to_string().ends_with("day08.rs already exists"));
+        assert!(!root.join("src/bin/08_halting.rs").exists());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
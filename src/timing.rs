@@ -0,0 +1,92 @@
+//! Timing of the individual steps of a solution, used by `--time`.
+
+use std::time::Duration;
+
+/// How long each step of solving a puzzle took in a single run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub read: Duration,
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+/// Summary of the durations measured over several runs.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+    pub runs: usize,
+}
+
+impl Stats {
+    /// Returns the statistics of the samples, which must not be empty.
+    ///
+    /// For an even number of samples, the median is the lower of the two
+    /// middle samples.
+    pub fn new(samples: &[Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples to compute stats from");
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        Stats {
+            min: sorted[0],
+            median: sorted[(sorted.len() - 1) / 2],
+            max: sorted[sorted.len() - 1],
+            runs: sorted.len(),
+        }
+    }
+}
+
+/// Returns the duration selected by `f` from each of the timings.
+pub(crate) fn samples<F>(timings: &[Timings], f: F) -> Vec<Duration>
+where
+    F: Fn(&Timings) -> Duration,
+{
+    timings.iter().map(f).collect()
+}
+
+/// Formats the duration with three significant digits in a suitable unit,
+/// such as `12.3µs` or `1.20s`.
+pub fn format_duration(d: Duration) -> String {
+    let nanos = d.as_nanos() as f64;
+    let (value, unit) = if nanos < 1e3 {
+        return format!("{}ns", nanos);
+    } else if nanos < 1e6 {
+        (nanos / 1e3, "µs")
+    } else if nanos < 1e9 {
+        (nanos / 1e6, "ms")
+    } else {
+        (nanos / 1e9, "s")
+    };
+    if value < 10.0 {
+        format!("{:.2}{}", value, unit)
+    } else if value < 100.0 {
+        format!("{:.1}{}", value, unit)
+    } else {
+        format!("{:.0}{}", value, unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let ms = Duration::from_millis;
+        let stats = Stats::new(&[ms(5), ms(1), ms(3), ms(4)]);
+        assert_eq!(stats.min, ms(1));
+        assert_eq!(stats.median, ms(3));
+        assert_eq!(stats.max, ms(5));
+        assert_eq!(stats.runs, 4);
+    }
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(512)), "512ns");
+        assert_eq!(format_duration(Duration::from_nanos(12_345)), "12.3µs");
+        assert_eq!(format_duration(Duration::from_micros(1_500)), "1.50ms");
+        assert_eq!(format_duration(Duration::from_millis(123_456)), "123s");
+    }
+}
@@ -0,0 +1,71 @@
+//! Waiting for input files to change, for `--watch`.
+
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// How often the input files are checked for changes.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// The modification times of a set of files, which are `None` for files that
+/// do not exist.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct Snapshot(Vec<(PathBuf, Option<SystemTime>)>);
+
+impl Snapshot {
+    pub fn new(paths: Vec<PathBuf>) -> Self {
+        Snapshot(
+            paths
+                .into_iter()
+                .map(|path| {
+                    let modified = fs::metadata(&path).and_then(|x| x.modified()).ok();
+                    (path, modified)
+                })
+                .collect(),
+        )
+    }
+
+    /// Returns true if any of the files has been modified, created, or
+    /// removed since the snapshot was taken.
+    pub fn changed(&self) -> bool {
+        let paths = self.0.iter().map(|(path, _)| path.clone()).collect();
+        Snapshot::new(paths) != *self
+    }
+
+    /// Blocks until any of the files changes.
+    pub fn wait_for_change(&self) {
+        while !self.changed() {
+            thread::sleep(POLL_INTERVAL);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::fs::File;
+    use std::process;
+
+    #[test]
+    fn test_changed() {
+        let dir = env::temp_dir().join(format!("aoc20-test-watch-{}", process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("input.txt");
+        let missing = dir.join("missing.txt");
+        fs::write(&path, "a").unwrap();
+
+        let snapshot = Snapshot::new(vec![path.clone(), missing.clone()]);
+        assert!(!snapshot.changed());
+
+        let file = File::options().write(true).open(&path).unwrap();
+        file.set_modified(SystemTime::UNIX_EPOCH).unwrap();
+        assert!(snapshot.changed());
+
+        let snapshot = Snapshot::new(vec![path, missing.clone()]);
+        fs::write(&missing, "b").unwrap();
+        assert!(snapshot.changed());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
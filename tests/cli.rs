@@ -0,0 +1,289 @@
+//! End-to-end tests that run the binaries on the built-in inputs and on the
+//! worked examples from the puzzle descriptions.
+
+use aoc20::examples::examples;
+use aoc20::{Day, ExpectedAnswers, DAYS, INPUT_DIR_VAR};
+
+use std::env;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{Command, Output, Stdio};
+
+/// The binary of each day, in order.
+const BINARIES: &[&str] = &[
+    env!("CARGO_BIN_EXE_01_report"),
+    env!("CARGO_BIN_EXE_02_passwords"),
+    env!("CARGO_BIN_EXE_03_trajectory"),
+    env!("CARGO_BIN_EXE_04_passport"),
+    env!("CARGO_BIN_EXE_05_boarding"),
+    env!("CARGO_BIN_EXE_06_customs"),
+    env!("CARGO_BIN_EXE_07_handy"),
+];
+
+const AOC20: &str = env!("CARGO_BIN_EXE_aoc20");
+
+/// The answers to both parts of each day for the built-in input.
+const ANSWERS: &[(&str, &str)] = &[
+    ("1019571", "100655544"),
+    ("458", "342"),
+    ("187", "4723283400"),
+    ("192", "101"),
+    ("864", "739"),
+    ("6763", "3512"),
+    ("246", "2976"),
+];
+
+/// Runs the binary with the arguments, writing `stdin` to its standard input.
+///
+/// The input directory is set to one that does not exist, so that days
+/// without an input argument use their built-in input, and `COLUMNS` is
+/// removed, so that `--describe` wraps to its default width.
+fn run(binary: &str, args: &[&str], stdin: Option<&str>) -> Output {
+    let mut child = Command::new(binary)
+        .args(args)
+        .env(INPUT_DIR_VAR, "does/not/exist")
+        .env_remove("COLUMNS")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut pipe = child.stdin.take().unwrap();
+    pipe.write_all(stdin.unwrap_or("").as_bytes()).unwrap();
+    drop(pipe);
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    stdout_with_code(output, 0)
+}
+
+/// Returns the standard output, after checking the exit status.
+fn stdout_with_code(output: &Output, code: i32) -> String {
+    assert_eq!(
+        output.status.code(),
+        Some(code),
+        "failed with {}: {}",
+        output.status,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+fn temp_dir(name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc20-cli-{}-{}", name, std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn expected_output(day: &Day, answers: &[(usize, Option<&str>)]) -> String {
+    let mut s = format!("Day {}: {}\n", day.day, day.puzzle);
+    for (part, answer) in answers {
+        match answer {
+            Some(answer) => s += &format!(":: Answer {} is {}\n", part, answer),
+            None => s += &format!(":: There is no answer {}\n", part),
+        }
+    }
+    s
+}
+
+#[test]
+fn test_default_inputs() {
+    for ((binary, day), (part1, part2)) in BINARIES.iter().zip(DAYS).zip(ANSWERS) {
+        let want = expected_output(day, &[(1, Some(part1)), (2, Some(part2))]);
+        assert_eq!(stdout(&run(binary, &[], None)), want);
+        assert_eq!(
+            stdout(&run(AOC20, &["run", &day.day.to_string()], None)),
+            want
+        );
+    }
+}
+
+#[test]
+fn test_examples_from_stdin_and_files() {
+    let dir = temp_dir("examples");
+    for (binary, day) in BINARIES.iter().zip(DAYS) {
+        for example in examples(day.description).unwrap() {
+            let want: Vec<_> = example
+                .expected
+                .iter()
+                .map(|(part, answer)| (*part, answer.as_deref()))
+                .collect();
+            let mut args = Vec::new();
+            if let [(part, _)] = want[..] {
+                args.extend(&["--part", if part == 1 { "1" } else { "2" }]);
+            }
+            // A part without an answer makes the run exit with status 3.
+            let missing = want.iter().find(|(_, answer)| answer.is_none());
+            let code = if missing.is_some() { 3 } else { 0 };
+            let mut stderr = ":: Reading from stdin...\n".to_string();
+            if let Some((part, _)) = missing {
+                stderr += &format!("error: there is no answer {} for day {}\n", part, day.day);
+            }
+            let want = expected_output(day, &want);
+
+            let mut stdin_args = args.clone();
+            stdin_args.push("-");
+            let output = run(binary, &stdin_args, Some(&example.input));
+            assert_eq!(
+                stdout_with_code(&output, code),
+                want,
+                "day {} line {}",
+                day.day,
+                example.line
+            );
+            assert_eq!(String::from_utf8_lossy(&output.stderr), stderr);
+
+            let path = dir.join(format!("day{:02}-{}.txt", day.day, example.line));
+            fs::write(&path, &example.input).unwrap();
+            let mut file_args = args.clone();
+            file_args.push(path.to_str().unwrap());
+            let output = run(binary, &file_args, None);
+            assert_eq!(
+                stdout_with_code(&output, code),
+                want,
+                "day {} line {}",
+                day.day,
+                example.line
+            );
+        }
+    }
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_missing_file() {
+    let output = run(BINARIES[3], &["does/not/exist.txt"], None);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "error: input file not found: does/not/exist.txt\n"
+    );
+}
+
+#[test]
+fn test_glob_without_match() {
+    let dir = temp_dir("glob");
+    let ok = dir.join("ok.txt");
+    fs::write(&ok, "1-3 a: abcde\n").unwrap();
+    let pattern = dir.join("nomatch*.txt");
+    let (ok, pattern) = (ok.to_str().unwrap(), pattern.to_str().unwrap());
+
+    let output = run(AOC20, &["run", "2", ok, pattern], None);
+    let stdout = stdout_with_code(&output, 1);
+    assert!(stdout
+        .lines()
+        .any(|x| x.split_whitespace().eq(vec![ok, "1", "1"])));
+    assert!(stdout.contains(&format!("error: no input file matches: {}\n", pattern)));
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_parse_error() {
+    let output = run(BINARIES[1], &["-"], Some("1-3 a: abcde\n1-3: cdefg\n"));
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).ends_with(
+        "error: invalid password policy: \"1-3\"
+ --> -:2:1
+  |
+2 | 1-3: cdefg
+  | ^^^ expected a range and a letter, such as `1-3 a`
+"
+    ));
+}
+
+#[test]
+fn test_no_answer() {
+    let output = run(BINARIES[4], &["-"], Some("FBFBBFFRLR\n"));
+    let want = expected_output(&DAYS[4], &[(1, Some("357")), (2, None)]);
+    assert_eq!(stdout_with_code(&output, 3), want);
+    assert!(String::from_utf8_lossy(&output.stderr)
+        .ends_with("error: there is no answer 2 for day 5\n"));
+
+    let output = run(BINARIES[4], &["-", "--part", "1"], Some("FBFBBFFRLR\n"));
+    assert_eq!(output.status.code(), Some(0));
+}
+
+#[test]
+fn test_cyclic_rules() {
+    let rules = "shiny gold bags contain 1 light red bag.\n\
+                 light red bags contain 1 dark blue bag.\n\
+                 dark blue bags contain 1 light red bag.\n";
+    let output = run(BINARIES[6], &["-", "--part", "2"], Some(rules));
+    assert_eq!(
+        stdout_with_code(&output, 3),
+        expected_output(&DAYS[6], &[(2, None)])
+    );
+    assert!(String::from_utf8_lossy(&output.stderr)
+        .ends_with("error: there is no answer 2 for day 7\n"));
+}
+
+#[test]
+fn test_day_options() {
+    let args = ["run", "1", "-", "--target", "10", "--k", "4"];
+    let output = run(AOC20, &args, Some("1\n2\n3\n4\n6\n"));
+    assert_eq!(
+        stdout(&output),
+        expected_output(&DAYS[0], &[(1, Some("24")), (2, Some("24"))])
+    );
+
+    for args in &[
+        &["run", "7", "--target", "5"][..],
+        &["run", "all", "--k", "9"],
+    ] {
+        let output = run(AOC20, args, None);
+        assert_eq!(output.status.code(), Some(1));
+        assert!(output.stdout.is_empty());
+    }
+    let output = run(BINARIES[6], &["--k", "9"], None);
+    assert_eq!(
+        String::from_utf8_lossy(&output.stderr),
+        "error: --k is not an option of day 7\n"
+    );
+}
+
+#[test]
+fn test_verbose_input_on_stderr() {
+    let output = run(BINARIES[0], &["-v", "--format", "json"], None);
+    let stdout = stdout(&output);
+    assert_eq!(stdout.lines().count(), 1);
+    assert!(stdout.starts_with('{'));
+    assert!(String::from_utf8_lossy(&output.stderr).starts_with(":: Program input is:\n1388\n"));
+}
+
+/// Checks the answers recorded in `answers.txt` for the built-in inputs,
+/// skipping days that have just been created with `aoc20 new` and have no
+/// answers recorded yet.
+#[test]
+fn test_check_all() {
+    let expected = ExpectedAnswers::from_file("answers.txt").unwrap();
+    let days: Vec<_> = DAYS
+        .iter()
+        .filter(|day| (1..=2).all(|part| expected.get(day.day, part, "default").is_some()))
+        .collect();
+    assert!(days.len() >= ANSWERS.len());
+    for day in days {
+        let output = run(AOC20, &["run", &day.day.to_string(), "--check"], None);
+        let stdout = stdout(&output);
+        assert_eq!(stdout.matches("[PASS]").count(), 2);
+        assert!(stdout.ends_with("\n:: Check PASSED\n"));
+    }
+}
+
+#[test]
+fn test_describe() {
+    let output = stdout(&run(BINARIES[0], &["--describe"], None));
+    assert!(output.starts_with("Day 1: Report Repair\n\n"));
+    assert!(output.contains("\nPart Two\n"));
+    assert!(!output.contains("<!--"));
+    assert!(output.lines().all(|x| x.len() <= 80));
+
+    let output = stdout(&run(
+        AOC20,
+        &["run", "1", "--describe", "--part", "2"],
+        None,
+    ));
+    assert!(output.starts_with("Part Two\n\n"));
+}